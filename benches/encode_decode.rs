@@ -0,0 +1,119 @@
+use bson::{doc, spec::BinarySubtype, Binary, Document};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use serde::{Deserialize, Serialize};
+
+fn flat_document() -> Document {
+    let mut doc = Document::new();
+    for i in 0..50 {
+        let key = format!("field{}", i);
+        match i % 5 {
+            0 => doc.insert(key, i),
+            1 => doc.insert(key, i as i64 * 1_000_000_000),
+            2 => doc.insert(key, i as f64 / 3.0),
+            3 => doc.insert(key, format!("value number {}", i)),
+            _ => doc.insert(key, i % 2 == 0),
+        };
+    }
+    doc
+}
+
+#[derive(Serialize, Deserialize)]
+struct Person {
+    name: String,
+    age: i32,
+    email: String,
+    verified: bool,
+    score: f64,
+    tags: Vec<String>,
+    address: Address,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Address {
+    street: String,
+    city: String,
+    zip: String,
+}
+
+fn person_document() -> Document {
+    doc! {
+        "name": "Ferris Crab",
+        "age": 7,
+        "email": "ferris@example.com",
+        "verified": true,
+        "score": 99.5,
+        "tags": ["rust", "bson", "serde"],
+        "address": { "street": "1 Ocean Drive", "city": "Coral Reef", "zip": "12345" },
+    }
+}
+
+fn encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+
+    let flat = flat_document();
+    group.throughput(Throughput::Bytes(bson::to_vec(&flat).unwrap().len() as u64));
+    group.bench_function("flat_50_fields", |b| {
+        b.iter(|| bson::encode_document_to_vec(black_box(&flat)).unwrap())
+    });
+
+    let blob = doc! {
+        "blob": Binary { subtype: BinarySubtype::Generic, bytes: vec![0xAB; 1024 * 1024] },
+    };
+    group.throughput(Throughput::Bytes(bson::to_vec(&blob).unwrap().len() as u64));
+    group.bench_function("binary_1mb", |b| {
+        b.iter(|| bson::encode_document_to_vec(black_box(&blob)).unwrap())
+    });
+
+    group.finish();
+}
+
+fn decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+
+    let bytes = bson::to_vec(&flat_document()).unwrap();
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+    group.bench_function("flat_50_fields", |b| {
+        b.iter(|| bson::decode_document_from_slice(black_box(&bytes)).unwrap())
+    });
+
+    let arrays: Document = (0..50)
+        .map(|i| (format!("array{}", i), bson::bson!([i, i + 1, i + 2, "x"])))
+        .collect();
+    let bytes = bson::to_vec(&arrays).unwrap();
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+    group.bench_function("small_arrays", |b| {
+        b.iter(|| bson::decode_document_from_slice(black_box(&bytes)).unwrap())
+    });
+
+    let bytes = bson::to_vec(&person_document()).unwrap();
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+    group.bench_function("typed_struct", |b| {
+        b.iter(|| bson::from_slice::<Person>(black_box(&bytes)).unwrap())
+    });
+
+    group.finish();
+}
+
+fn round_trip(c: &mut Criterion) {
+    let mut group = c.benchmark_group("round_trip");
+
+    let docs: Vec<Document> = (0..10_000).map(|i| doc! { "_id": i, "x": "y" }).collect();
+    group.throughput(Throughput::Elements(docs.len() as u64));
+    group.bench_function("small_documents_10k", |b| {
+        b.iter_batched(
+            || docs.clone(),
+            |docs| {
+                for doc in docs {
+                    let bytes = bson::to_vec(&doc).unwrap();
+                    black_box(bson::from_slice::<Document>(&bytes).unwrap());
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, encode, decode, round_trip);
+criterion_main!(benches);
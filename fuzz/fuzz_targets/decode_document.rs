@@ -0,0 +1,9 @@
+#![no_main]
+#[macro_use] extern crate libfuzzer_sys;
+extern crate bson;
+use bson::DocumentReader;
+
+fuzz_target!(|buf: &[u8]| {
+    let _ = bson::decode_document_from_slice(buf);
+    for _ in DocumentReader::new(buf) {}
+});
@@ -22,8 +22,12 @@
 //! BSON definition
 
 use std::{
+    borrow::Cow,
+    cmp::Ordering,
     convert::{TryFrom, TryInto},
     fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    mem,
 };
 
 use serde_json::{json, Value};
@@ -37,9 +41,26 @@ use crate::{
 };
 
 /// Possible BSON value types.
-#[derive(Clone, PartialEq)]
+///
+/// Unlike `f64`, `Bson::Double` values holding NaN compare equal to each other, which allows `Bson`
+/// to implement [`Eq`] and [`Hash`] and be used as a `HashMap` or `HashSet` key.
+///
+/// ```
+/// use bson::{bson, Bson};
+///
+/// let value = bson!({ "name": "Ferris", "tags": ["crab", 1i64] });
+/// let doc = value.as_document().unwrap();
+/// assert_eq!(doc.get("name"), Some(&Bson::String("Ferris".to_string())));
+/// assert_eq!(doc.get_array("tags")?[1], Bson::Int64(1));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone)]
 pub enum Bson {
     /// 64-bit binary floating point
+    ///
+    /// BSON has no 32-bit floating point type, so `f32` values are widened to `f64` when
+    /// serialized. The conversion is exact, but the widened value is not the nearest `f64` to
+    /// the decimal literal the `f32` was written as (e.g. `0.1f32 as f64 != 0.1`).
     Double(f64),
     /// UTF-8 string
     String(String),
@@ -86,12 +107,35 @@ pub enum Bson {
 /// Alias for `Vec<Bson>`.
 pub type Array = Vec<Bson>;
 
+// `#[derive(Default)]` with a `#[default]` variant needs Rust 1.62, above the MSRV.
+#[allow(clippy::derivable_impls)]
 impl Default for Bson {
     fn default() -> Self {
         Bson::Null
     }
 }
 
+/// Alias for [`Cow<'a, Bson>`](Cow), a value that is either borrowed from an existing [`Bson`] or
+/// owned.
+///
+/// Read-only code such as query evaluation can hand out borrowed values from a decoded document
+/// and only clone them when a modified value needs to be produced. It dereferences to [`Bson`],
+/// and converts into an owned [`Bson`] via [`Into`], which clones the value only if it is
+/// borrowed.
+///
+/// ```
+/// use std::borrow::Cow;
+/// use bson::{doc, Bson, CowBson};
+///
+/// let doc = doc! { "x": 1 };
+/// let value: CowBson = Cow::Borrowed(doc.get("x").unwrap());
+/// assert_eq!(value.as_i32(), Some(1));
+///
+/// let owned: Bson = value.into();
+/// assert_eq!(owned, Bson::Int32(1));
+/// ```
+pub type CowBson<'a> = Cow<'a, Bson>;
+
 impl Display for Bson {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -173,6 +217,72 @@ impl Debug for Bson {
     }
 }
 
+impl PartialEq for Bson {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Bson::Double(a), Bson::Double(b)) => a == b || (a.is_nan() && b.is_nan()),
+            (Bson::String(a), Bson::String(b)) => a == b,
+            (Bson::Array(a), Bson::Array(b)) => a == b,
+            (Bson::Document(a), Bson::Document(b)) => a == b,
+            (Bson::Boolean(a), Bson::Boolean(b)) => a == b,
+            (Bson::Null, Bson::Null) => true,
+            (Bson::RegularExpression(a), Bson::RegularExpression(b)) => a == b,
+            (Bson::JavaScriptCode(a), Bson::JavaScriptCode(b)) => a == b,
+            (Bson::JavaScriptCodeWithScope(a), Bson::JavaScriptCodeWithScope(b)) => a == b,
+            (Bson::Int32(a), Bson::Int32(b)) => a == b,
+            (Bson::Int64(a), Bson::Int64(b)) => a == b,
+            (Bson::Timestamp(a), Bson::Timestamp(b)) => a == b,
+            (Bson::Binary(a), Bson::Binary(b)) => a == b,
+            (Bson::ObjectId(a), Bson::ObjectId(b)) => a == b,
+            (Bson::DateTime(a), Bson::DateTime(b)) => a == b,
+            (Bson::Symbol(a), Bson::Symbol(b)) => a == b,
+            (Bson::Decimal128(a), Bson::Decimal128(b)) => a == b,
+            (Bson::Undefined, Bson::Undefined) => true,
+            (Bson::MaxKey, Bson::MaxKey) => true,
+            (Bson::MinKey, Bson::MinKey) => true,
+            (Bson::DbPointer(a), Bson::DbPointer(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Bson {}
+
+/// `Double` values are hashed by their bit pattern, except that `0.0` and `-0.0` hash identically
+/// and all NaN bit patterns hash identically, matching the [`PartialEq`] implementation.
+impl Hash for Bson {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Bson::Double(f) => {
+                let bits = if f.is_nan() {
+                    f64::NAN.to_bits()
+                } else if *f == 0.0 {
+                    0.0f64.to_bits()
+                } else {
+                    f.to_bits()
+                };
+                bits.hash(state);
+            }
+            Bson::String(s) | Bson::JavaScriptCode(s) | Bson::Symbol(s) => s.hash(state),
+            Bson::Array(array) => array.hash(state),
+            Bson::Document(doc) => doc.hash(state),
+            Bson::Boolean(b) => b.hash(state),
+            Bson::RegularExpression(regex) => regex.hash(state),
+            Bson::JavaScriptCodeWithScope(code) => code.hash(state),
+            Bson::Int32(i) => i.hash(state),
+            Bson::Int64(i) => i.hash(state),
+            Bson::Timestamp(t) => t.hash(state),
+            Bson::Binary(b) => b.hash(state),
+            Bson::ObjectId(id) => id.hash(state),
+            Bson::DateTime(date_time) => date_time.hash(state),
+            Bson::Decimal128(d) => d.hash(state),
+            Bson::DbPointer(pointer) => pointer.hash(state),
+            Bson::Null | Bson::Undefined | Bson::MinKey | Bson::MaxKey => {}
+        }
+    }
+}
+
 impl From<f32> for Bson {
     fn from(a: f32) -> Bson {
         Bson::Double(a.into())
@@ -242,6 +352,12 @@ where
     }
 }
 
+impl<'a> From<Cow<'a, Bson>> for Bson {
+    fn from(cow: Cow<'a, Bson>) -> Bson {
+        cow.into_owned()
+    }
+}
+
 impl<T> From<Vec<T>> for Bson
 where
     T: Into<Bson>,
@@ -381,8 +497,6 @@ impl From<Bson> for Value {
 
 impl Bson {
     /// Converts the Bson value into its [relaxed extended JSON representation](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/).
-    ///
-    /// Note: If this method is called on a case which contains a `Decimal128` value, it will panic.
     pub fn into_relaxed_extjson(self) -> Value {
         match self {
             Bson::Double(v) if v.is_nan() => {
@@ -455,7 +569,7 @@ impl Bson {
                 "$date": { "$numberLong": v.timestamp_millis().to_string() },
             }),
             Bson::Symbol(v) => json!({ "$symbol": v }),
-            Bson::Decimal128(_) => panic!("Decimal128 extended JSON not implemented yet."),
+            Bson::Decimal128(v) => json!({ "$numberDecimal": v.to_spec_string() }),
             Bson::Undefined => json!({ "$undefined": true }),
             Bson::MinKey => json!({ "$minKey": 1 }),
             Bson::MaxKey => json!({ "$maxKey": 1 }),
@@ -474,9 +588,6 @@ impl Bson {
     }
 
     /// Converts the Bson value into its [canonical extended JSON representation](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/).
-    ///
-    /// Note: extended json encoding for `Decimal128` values is not supported. If this method is
-    /// called on a case which contains a `Decimal128` value, it will panic.
     pub fn into_canonical_extjson(self) -> Value {
         match self {
             Bson::Int32(i) => json!({ "$numberInt": i.to_string() }),
@@ -514,6 +625,78 @@ impl Bson {
         }
     }
 
+    /// Parses a [`Bson`] value from its [extended JSON v2 representation](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/).
+    ///
+    /// Relaxed extJSON is accepted, as is canonical extJSON; the two modes may be mixed within a
+    /// single value. This is equivalent to this type's `TryFrom<serde_json::Value>`
+    /// implementation.
+    ///
+    /// ```
+    /// use bson::{oid::ObjectId, Bson, DateTime};
+    /// use serde_json::json;
+    ///
+    /// let value = Bson::from_relaxed_extjson(json!({
+    ///     "_id": { "$oid": "507f1f77bcf86cd799439011" },
+    ///     "at": { "$date": "2021-01-01T00:00:00Z" },
+    ///     "price": { "$numberDecimal": "1.23E+10" },
+    /// }))?;
+    ///
+    /// let doc = value.as_document().unwrap();
+    /// assert_eq!(doc.get_object_id("_id")?, ObjectId::parse_str("507f1f77bcf86cd799439011")?);
+    /// assert_eq!(doc.get_datetime("at")?, &DateTime::parse_rfc3339_str("2021-01-01T00:00:00Z")?);
+    /// assert!(matches!(doc.get("price"), Some(Bson::Decimal128(_))));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_relaxed_extjson(value: Value) -> crate::extjson::de::Result<Bson> {
+        Bson::try_from(value)
+    }
+
+    /// Returns the [canonical extended JSON representation](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/)
+    /// of this value without consuming it.
+    ///
+    /// All numeric types are wrapped in their type-preserving forms (e.g. `{"$numberInt":"5"}`),
+    /// so the output can be fed to tools such as `mongoimport` without losing type information.
+    ///
+    /// ```
+    /// use bson::{bson, DateTime};
+    /// use serde_json::json;
+    ///
+    /// let value = bson!({ "x": 5, "y": 5_i64, "z": 1.5, "when": DateTime::from_millis(0) });
+    /// assert_eq!(
+    ///     value.to_canonical_extjson(),
+    ///     json!({
+    ///         "x": { "$numberInt": "5" },
+    ///         "y": { "$numberLong": "5" },
+    ///         "z": { "$numberDouble": "1.5" },
+    ///         "when": { "$date": { "$numberLong": "0" } },
+    ///     })
+    /// );
+    /// ```
+    pub fn to_canonical_extjson(&self) -> Value {
+        self.clone().into_canonical_extjson()
+    }
+
+    /// Renders this value as a [relaxed extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/) string.
+    ///
+    /// Unlike the output of this type's `Display` implementation, which mimics the MongoDB shell,
+    /// the returned string is always valid JSON (e.g. an `ObjectId` is rendered as
+    /// `{"$oid":"..."}`).
+    ///
+    /// ```
+    /// use bson::{bson, oid::ObjectId};
+    ///
+    /// let oid = ObjectId::parse_str("507f1f77bcf86cd799439011")?;
+    /// let value = bson!({ "_id": oid, "x": 5, "tags": ["a"] });
+    /// assert_eq!(
+    ///     value.to_json_string(),
+    ///     r#"{"_id":{"$oid":"507f1f77bcf86cd799439011"},"x":5,"tags":["a"]}"#
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        self.clone().into_relaxed_extjson().to_string()
+    }
+
     /// Get the `ElementType` of this value.
     pub fn element_type(&self) -> ElementType {
         match *self {
@@ -541,6 +724,41 @@ impl Bson {
         }
     }
 
+    /// Returns a short, human-readable name for the type of this value, for use in display and
+    /// error messages (e.g. `"expected string, got double"`).
+    ///
+    /// ```
+    /// use bson::Bson;
+    ///
+    /// assert_eq!(Bson::Double(1.5).type_name(), "double");
+    /// assert_eq!(Bson::from("hello").type_name(), "string");
+    /// ```
+    pub fn type_name(&self) -> &'static str {
+        match *self {
+            Bson::Double(..) => "double",
+            Bson::String(..) => "string",
+            Bson::Array(..) => "array",
+            Bson::Document(..) => "document",
+            Bson::Boolean(..) => "bool",
+            Bson::Null => "null",
+            Bson::RegularExpression(..) => "regex",
+            Bson::JavaScriptCode(..) => "javascript",
+            Bson::JavaScriptCodeWithScope(..) => "javascriptWithScope",
+            Bson::Int32(..) => "int32",
+            Bson::Int64(..) => "int64",
+            Bson::Timestamp(..) => "timestamp",
+            Bson::Binary(..) => "binary",
+            Bson::ObjectId(..) => "objectId",
+            Bson::DateTime(..) => "date",
+            Bson::Symbol(..) => "symbol",
+            Bson::Decimal128(..) => "decimal128",
+            Bson::Undefined => "undefined",
+            Bson::MaxKey => "maxKey",
+            Bson::MinKey => "minKey",
+            Bson::DbPointer(..) => "dbPointer",
+        }
+    }
+
     /// Converts to extended format.
     /// This function mainly used for [extended JSON format](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/).
     // TODO RUST-426: Investigate either removing this from the serde implementation or unifying
@@ -654,6 +872,12 @@ impl Bson {
         }
     }
 
+    /// Converts a document in extended JSON format into the BSON value it represents, leaving it
+    /// as a document if it isn't one.
+    ///
+    /// A document that only resembles extended JSON, e.g. a single `$oid` key whose value is not a
+    /// valid object ID, is also left as a document, matching what [`crate::to_vec`] produces for
+    /// it.
     pub(crate) fn from_extended_document(doc: Document) -> Bson {
         if doc.len() > 2 {
             return Bson::Document(doc);
@@ -694,9 +918,9 @@ impl Bson {
             }
 
             ["$numberDouble"] => match doc.get_str("$numberDouble") {
-                Ok("Infinity") => return Bson::Double(std::f64::INFINITY),
-                Ok("-Infinity") => return Bson::Double(std::f64::NEG_INFINITY),
-                Ok("NaN") => return Bson::Double(std::f64::NAN),
+                Ok("Infinity") => return Bson::Double(f64::INFINITY),
+                Ok("-Infinity") => return Bson::Double(f64::NEG_INFINITY),
+                Ok("NaN") => return Bson::Double(f64::NAN),
                 Ok(other) => {
                     if let Ok(d) = other.parse() {
                         return Bson::Double(d);
@@ -749,10 +973,7 @@ impl Bson {
 
                     if let Ok(t) = timestamp.get_i64("t") {
                         if let Ok(i) = timestamp.get_i64("i") {
-                            if t >= 0
-                                && i >= 0
-                                && t <= (std::u32::MAX as i64)
-                                && i <= (std::u32::MAX as i64)
+                            if t >= 0 && i >= 0 && t <= (u32::MAX as i64) && i <= (u32::MAX as i64)
                             {
                                 return Bson::Timestamp(Timestamp {
                                     time: t as u32,
@@ -815,10 +1036,8 @@ impl Bson {
                 }
             }
 
-            ["$undefined"] => {
-                if doc.get("$undefined") == Some(&Bson::Boolean(true)) {
-                    return Bson::Undefined;
-                }
+            ["$undefined"] if doc.get("$undefined") == Some(&Bson::Boolean(true)) => {
+                return Bson::Undefined;
             }
 
             _ => {}
@@ -849,6 +1068,19 @@ impl Bson {
         }
     }
 
+    /// Returns `true` if `Bson` is a `Double` holding a NaN value. Returns `false` otherwise
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        matches!(*self, Bson::Double(v) if v.is_nan())
+    }
+
+    /// Returns `true` if `Bson` is a `Double` holding positive or negative infinity. Returns
+    /// `false` otherwise
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        matches!(*self, Bson::Double(v) if v.is_infinite())
+    }
+
     /// If `Bson` is `String`, return its value as a `&str`. Returns `None` otherwise
     pub fn as_str(&self) -> Option<&str> {
         match *self {
@@ -882,6 +1114,40 @@ impl Bson {
         }
     }
 
+    /// If `Bson` is `Array`, return its number of elements. Returns `None` otherwise
+    pub fn array_len(&self) -> Option<usize> {
+        self.as_array().map(Vec::len)
+    }
+
+    /// If `Bson` is `Array`, return a reference to the element at `index`. Returns `None` if it is
+    /// not an array or the index is out of bounds
+    pub fn array_get(&self, index: usize) -> Option<&Bson> {
+        self.as_array().and_then(|array| array.get(index))
+    }
+
+    /// If `Bson` is `Array`, return a mutable reference to the element at `index`. Returns `None`
+    /// if it is not an array or the index is out of bounds
+    pub fn array_get_mut(&mut self, index: usize) -> Option<&mut Bson> {
+        self.as_array_mut().and_then(|array| array.get_mut(index))
+    }
+
+    /// If `Bson` is `Array`, append `value` to it and return `true`. Returns `false`, dropping
+    /// `value`, otherwise
+    pub fn array_push(&mut self, value: impl Into<Bson>) -> bool {
+        match self.as_array_mut() {
+            Some(array) => {
+                array.push(value.into());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// If `Bson` is `Array`, return an iterator over its elements. Returns `None` otherwise
+    pub fn array_iter(&self) -> Option<std::slice::Iter<'_, Bson>> {
+        self.as_array().map(|array| array.iter())
+    }
+
     /// If `Bson` is `Document`, return its value. Returns `None` otherwise
     pub fn as_document(&self) -> Option<&Document> {
         match *self {
@@ -993,6 +1259,428 @@ impl Bson {
             _ => None,
         }
     }
+
+    /// If `Bson` is `Binary`, return a reference to its bytes, regardless of its subtype. Returns
+    /// `None` otherwise
+    pub fn as_binary_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Bson::Binary(ref binary) => Some(&binary.bytes),
+            _ => None,
+        }
+    }
+
+    /// Creates a `Bson::Binary` with the UUID subtype (4) from the given bytes, which are stored
+    /// as-is in the standard (RFC 4122) byte order.
+    pub fn new_uuid(uuid_bytes: [u8; 16]) -> Bson {
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::Uuid,
+            bytes: uuid_bytes.to_vec(),
+        })
+    }
+
+    /// If `Bson` is `Binary` with the UUID subtype (4) and holds exactly 16 bytes, return those
+    /// bytes. Returns `None` otherwise
+    pub fn as_uuid(&self) -> Option<[u8; 16]> {
+        match self {
+            Bson::Binary(ref binary) if binary.subtype == BinarySubtype::Uuid => {
+                binary.bytes.as_slice().try_into().ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Creates a `Bson::Binary` holding a UUID in the given representation, where `uuid_bytes`
+    /// are in the standard (RFC 4122) byte order.
+    ///
+    /// The legacy representations use the old UUID subtype (3). The Java and C# drivers
+    /// historically reordered the bytes when storing them, and the bytes are reordered here to
+    /// match. [`UuidRepresentation::Standard`](crate::UuidRepresentation::Standard) produces the
+    /// same value as [`Bson::new_uuid`].
+    ///
+    /// ```
+    /// use bson::{spec::BinarySubtype, Binary, Bson, UuidRepresentation};
+    ///
+    /// let uuid = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    /// let bson = Bson::new_legacy_uuid(uuid, UuidRepresentation::JavaLegacy);
+    /// assert_eq!(
+    ///     bson,
+    ///     Bson::Binary(Binary {
+    ///         subtype: BinarySubtype::UuidOld,
+    ///         bytes: vec![7, 6, 5, 4, 3, 2, 1, 0, 15, 14, 13, 12, 11, 10, 9, 8],
+    ///     })
+    /// );
+    /// assert_eq!(bson.as_legacy_uuid(UuidRepresentation::JavaLegacy), Some(uuid));
+    /// ```
+    pub fn new_legacy_uuid(uuid_bytes: [u8; 16], rep: crate::UuidRepresentation) -> Bson {
+        Bson::Binary(Binary::from_uuid_with_representation(
+            crate::Uuid::from_bytes(uuid_bytes),
+            rep,
+        ))
+    }
+
+    /// If `Bson` is a `Binary` holding a UUID in the given representation, return its bytes in
+    /// the standard (RFC 4122) byte order. Returns `None` otherwise, including when the binary
+    /// subtype doesn't match the representation (3 for the legacy representations, 4 for
+    /// [`UuidRepresentation::Standard`](crate::UuidRepresentation::Standard)).
+    pub fn as_legacy_uuid(&self, rep: crate::UuidRepresentation) -> Option<[u8; 16]> {
+        match self {
+            Bson::Binary(ref binary) => binary
+                .to_uuid_with_representation(rep)
+                .ok()
+                .map(|uuid| uuid.bytes()),
+            _ => None,
+        }
+    }
+
+    /// Converts `Bson::Null` into `None` and any other value into `Some`, the inverse of
+    /// `Bson::from(Option<T>)`.
+    ///
+    /// This is an inherent method rather than a `From` impl because the standard library's
+    /// `impl<T> From<T> for Option<T>` already converts a `Bson` into `Some(bson)`.
+    ///
+    /// ```
+    /// use bson::Bson;
+    ///
+    /// assert_eq!(Bson::Null.into_option(), None);
+    /// assert_eq!(Bson::Int32(1).into_option(), Some(Bson::Int32(1)));
+    /// assert_eq!(Bson::from(None::<i32>), Bson::Null);
+    /// ```
+    pub fn into_option(self) -> Option<Bson> {
+        match self {
+            Bson::Null => None,
+            other => Some(other),
+        }
+    }
+
+    /// Returns whether this value is equal to `value` once it has been converted into a `Bson`.
+    ///
+    /// `Bson` does not implement `PartialEq` for plain Rust values, since such impls break type
+    /// inference for comparisons like `bson == value.into()`, so this method can be used instead.
+    /// As with `Bson`'s own `PartialEq`, the variants must match, so e.g. `Bson::Int64(1)` is not
+    /// equal to `1i32`. To compare only the underlying value, use an accessor such as
+    /// [`Bson::as_i64`].
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let doc = doc! { "name": "Alice", "count": 42 };
+    /// assert!(doc["name"].eq_value("Alice"));
+    /// assert!(doc["count"].eq_value(42));
+    /// assert!(!doc["count"].eq_value(42i64));
+    /// assert_eq!(doc["count"].as_i32(), Some(42));
+    /// ```
+    pub fn eq_value(&self, value: impl Into<Bson>) -> bool {
+        *self == value.into()
+    }
+
+    /// Returns `true` if `Bson` is `Null`. Returns `false` otherwise
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self, Bson::Null)
+    }
+
+    /// Returns `true` if `Bson` is a `Boolean`. Returns `false` otherwise
+    #[inline]
+    pub fn is_boolean(&self) -> bool {
+        matches!(self, Bson::Boolean(_))
+    }
+
+    /// Returns `true` if `Bson` is an `Int32`. Returns `false` otherwise
+    #[inline]
+    pub fn is_i32(&self) -> bool {
+        matches!(self, Bson::Int32(_))
+    }
+
+    /// Returns `true` if `Bson` is an `Int64`. Returns `false` otherwise
+    #[inline]
+    pub fn is_i64(&self) -> bool {
+        matches!(self, Bson::Int64(_))
+    }
+
+    /// Returns `true` if `Bson` is a `Double`. Returns `false` otherwise
+    #[inline]
+    pub fn is_f64(&self) -> bool {
+        matches!(self, Bson::Double(_))
+    }
+
+    /// Returns `true` if `Bson` is an `Int32`, `Int64`, or `Double`. Returns `false` otherwise
+    #[inline]
+    pub fn is_number(&self) -> bool {
+        matches!(self, Bson::Int32(_) | Bson::Int64(_) | Bson::Double(_))
+    }
+
+    /// Returns `true` if `Bson` is a `String`. Returns `false` otherwise
+    #[inline]
+    pub fn is_string(&self) -> bool {
+        matches!(self, Bson::String(_))
+    }
+
+    /// Returns `true` if `Bson` is a `Document`. Returns `false` otherwise
+    #[inline]
+    pub fn is_document(&self) -> bool {
+        matches!(self, Bson::Document(_))
+    }
+
+    /// Returns `true` if `Bson` is an `Array`. Returns `false` otherwise
+    #[inline]
+    pub fn is_array(&self) -> bool {
+        matches!(self, Bson::Array(_))
+    }
+
+    /// Returns `true` if `Bson` is an `ObjectId`. Returns `false` otherwise
+    #[inline]
+    pub fn is_object_id(&self) -> bool {
+        matches!(self, Bson::ObjectId(_))
+    }
+
+    /// Returns `true` if `Bson` is a `Binary`. Returns `false` otherwise
+    #[inline]
+    pub fn is_binary(&self) -> bool {
+        matches!(self, Bson::Binary(_))
+    }
+
+    /// Returns `true` if `Bson` is a `DateTime`. Returns `false` otherwise
+    #[inline]
+    pub fn is_datetime(&self) -> bool {
+        matches!(self, Bson::DateTime(_))
+    }
+
+    /// Returns `true` if `Bson` is a `RegularExpression`. Returns `false` otherwise
+    #[inline]
+    pub fn is_regex(&self) -> bool {
+        matches!(self, Bson::RegularExpression(_))
+    }
+
+    /// Returns `true` if `Bson` is `MinKey`. Returns `false` otherwise
+    #[inline]
+    pub fn is_min_key(&self) -> bool {
+        matches!(self, Bson::MinKey)
+    }
+
+    /// Returns `true` if `Bson` is `MaxKey`. Returns `false` otherwise
+    #[inline]
+    pub fn is_max_key(&self) -> bool {
+        matches!(self, Bson::MaxKey)
+    }
+}
+
+/// Comparison
+impl Bson {
+    /// Compares two values according to the [BSON comparison order](https://www.mongodb.com/docs/manual/reference/bson-type-comparison-order/)
+    /// used by MongoDB when sorting.
+    ///
+    /// Values of different types are ordered as follows, from least to greatest:
+    ///
+    /// 1. `MinKey`
+    /// 2. `Undefined`
+    /// 3. `Null`
+    /// 4. Numbers (`Int32`, `Int64`, `Double`, `Decimal128`)
+    /// 5. `Symbol`, `String`
+    /// 6. `Document`
+    /// 7. `Array`
+    /// 8. `Binary`
+    /// 9. `ObjectId`
+    /// 10. `Boolean`
+    /// 11. `DateTime`
+    /// 12. `Timestamp`
+    /// 13. `RegularExpression`
+    /// 14. `DbPointer`
+    /// 15. `JavaScriptCode`
+    /// 16. `JavaScriptCodeWithScope`
+    /// 17. `MaxKey`
+    ///
+    /// Numbers are compared by their numeric value regardless of their type, so `Int32(5)`,
+    /// `Int64(5)` and `Double(5.0)` compare as equal. NaN compares equal to itself and less than
+    /// every other number. `Decimal128` values are compared using their nearest `f64`
+    /// approximation. Symbols and strings are compared by their UTF-8 bytes, and documents and
+    /// arrays are compared element by element.
+    ///
+    /// Because this ordering considers values of different types equal, it is not consistent with
+    /// the [`PartialEq`] implementation for `Bson`, which is why `Bson` does not implement
+    /// [`Ord`]. It can be used with methods such as [`slice::sort_by`] instead.
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use bson::Bson;
+    ///
+    /// assert_eq!(Bson::Int32(5).total_cmp(&Bson::Double(5.0)), Ordering::Equal);
+    /// assert_eq!(Bson::Null.total_cmp(&Bson::Int64(0)), Ordering::Less);
+    ///
+    /// let mut values = vec![Bson::MaxKey, Bson::String("a".into()), Bson::Int32(1), Bson::MinKey];
+    /// values.sort_by(Bson::total_cmp);
+    /// assert_eq!(
+    ///     values,
+    ///     vec![Bson::MinKey, Bson::Int32(1), Bson::String("a".into()), Bson::MaxKey]
+    /// );
+    /// ```
+    pub fn total_cmp(&self, other: &Bson) -> Ordering {
+        let by_type = self
+            .canonical_type_order()
+            .cmp(&other.canonical_type_order());
+        if by_type != Ordering::Equal {
+            return by_type;
+        }
+
+        match (self, other) {
+            (Bson::String(a) | Bson::Symbol(a), Bson::String(b) | Bson::Symbol(b)) => a.cmp(b),
+            (Bson::Document(a), Bson::Document(b)) => cmp_documents(a, b),
+            (Bson::Array(a), Bson::Array(b)) => cmp_by(a, b, Bson::total_cmp),
+            (Bson::Binary(a), Bson::Binary(b)) => a
+                .bytes
+                .len()
+                .cmp(&b.bytes.len())
+                .then_with(|| u8::from(a.subtype).cmp(&u8::from(b.subtype)))
+                .then_with(|| a.bytes.cmp(&b.bytes)),
+            (Bson::ObjectId(a), Bson::ObjectId(b)) => a.cmp(b),
+            (Bson::Boolean(a), Bson::Boolean(b)) => a.cmp(b),
+            (Bson::DateTime(a), Bson::DateTime(b)) => a.cmp(b),
+            (Bson::Timestamp(a), Bson::Timestamp(b)) => a.cmp(b),
+            (Bson::RegularExpression(a), Bson::RegularExpression(b)) => a
+                .pattern
+                .cmp(&b.pattern)
+                .then_with(|| a.options.cmp(&b.options)),
+            (Bson::DbPointer(a), Bson::DbPointer(b)) => {
+                a.namespace.cmp(&b.namespace).then_with(|| a.id.cmp(&b.id))
+            }
+            (Bson::JavaScriptCode(a), Bson::JavaScriptCode(b)) => a.cmp(b),
+            (Bson::JavaScriptCodeWithScope(a), Bson::JavaScriptCodeWithScope(b)) => a
+                .code
+                .cmp(&b.code)
+                .then_with(|| cmp_documents(&a.scope, &b.scope)),
+            (a, b) => match (a.as_comparable_number(), b.as_comparable_number()) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                // All remaining types only have a single possible value.
+                _ => Ordering::Equal,
+            },
+        }
+    }
+
+    /// The rank of this value's type in the BSON comparison order. Types that compare against each
+    /// other by value share a rank.
+    fn canonical_type_order(&self) -> u8 {
+        match self {
+            Bson::MinKey => 0,
+            Bson::Undefined => 1,
+            Bson::Null => 2,
+            Bson::Int32(_) | Bson::Int64(_) | Bson::Double(_) | Bson::Decimal128(_) => 3,
+            Bson::Symbol(_) | Bson::String(_) => 4,
+            Bson::Document(_) => 5,
+            Bson::Array(_) => 6,
+            Bson::Binary(_) => 7,
+            Bson::ObjectId(_) => 8,
+            Bson::Boolean(_) => 9,
+            Bson::DateTime(_) => 10,
+            Bson::Timestamp(_) => 11,
+            Bson::RegularExpression(_) => 12,
+            Bson::DbPointer(_) => 13,
+            Bson::JavaScriptCode(_) => 14,
+            Bson::JavaScriptCodeWithScope(_) => 15,
+            Bson::MaxKey => 16,
+        }
+    }
+
+    fn as_comparable_number(&self) -> Option<ComparableNumber> {
+        match *self {
+            Bson::Int32(i) => Some(ComparableNumber::Int(i.into())),
+            Bson::Int64(i) => Some(ComparableNumber::Int(i)),
+            Bson::Double(f) => Some(ComparableNumber::Float(f)),
+            Bson::Decimal128(d) => Some(ComparableNumber::Float(
+                d.to_spec_string().parse().unwrap_or(f64::NAN),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Compares two documents element by element, ordering each pair of elements first by the type of
+/// their values, then by their keys, and then by the values themselves.
+fn cmp_documents(a: &Document, b: &Document) -> Ordering {
+    cmp_by(a.iter(), b.iter(), |(ak, av), (bk, bv)| {
+        av.canonical_type_order()
+            .cmp(&bv.canonical_type_order())
+            .then_with(|| ak.cmp(bk))
+            .then_with(|| av.total_cmp(bv))
+    })
+}
+
+fn cmp_by<I, F>(a: I, b: I, mut cmp: F) -> Ordering
+where
+    I: IntoIterator,
+    F: FnMut(I::Item, I::Item) -> Ordering,
+{
+    let mut b = b.into_iter();
+    for a in a {
+        let b = match b.next() {
+            Some(b) => b,
+            None => return Ordering::Greater,
+        };
+        let ordering = cmp(a, b);
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    if b.next().is_some() {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// A numeric value that can be compared across BSON number types without loss of precision.
+#[derive(Clone, Copy)]
+enum ComparableNumber {
+    Int(i64),
+    Float(f64),
+}
+
+impl ComparableNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (*self, *other) {
+            (Self::Int(a), Self::Int(b)) => a.cmp(&b),
+            (Self::Float(a), Self::Float(b)) => cmp_floats(a, b),
+            (Self::Int(a), Self::Float(b)) => cmp_int_float(a, b),
+            (Self::Float(a), Self::Int(b)) => cmp_int_float(b, a).reverse(),
+        }
+    }
+}
+
+/// Compares two floats, treating NaN as equal to itself and less than every other value.
+fn cmp_floats(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        // Unwrap safety: neither value is NaN.
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+/// Compares an integer against a float exactly, rather than by converting the integer to a float
+/// and losing precision.
+fn cmp_int_float(i: i64, f: f64) -> Ordering {
+    // 2^63, the smallest float that is larger than every i64.
+    const I64_UPPER_BOUND: f64 = 9_223_372_036_854_775_808.0;
+
+    if f.is_nan() {
+        Ordering::Greater
+    } else if f >= I64_UPPER_BOUND {
+        Ordering::Less
+    } else if f < -I64_UPPER_BOUND {
+        Ordering::Greater
+    } else {
+        // The float is within the range of i64, so its integral part converts exactly.
+        let truncated = f.trunc();
+        i.cmp(&(truncated as i64)).then_with(|| {
+            if f > truncated {
+                Ordering::Less
+            } else if f < truncated {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+    }
 }
 
 /// Represents a BSON timestamp value.
@@ -1013,25 +1701,47 @@ impl Display for Timestamp {
 }
 
 impl Timestamp {
+    /// Creates a timestamp for the current system time, with an `increment` of 0.
+    pub fn now() -> Self {
+        std::time::SystemTime::now().into()
+    }
+
+    /// Packs this timestamp into the 64-bit integer whose little-endian encoding is the BSON
+    /// representation: the increment in the low 32 bits and the time in the high 32 bits.
     pub(crate) fn to_le_i64(self) -> i64 {
-        let upper = (self.time.to_le() as u64) << 32;
-        let lower = self.increment.to_le() as u64;
+        let upper = (self.time as u64) << 32;
+        let lower = self.increment as u64;
 
         (upper | lower) as i64
     }
 
+    /// The inverse of [`Timestamp::to_le_i64`].
     pub(crate) fn from_le_i64(val: i64) -> Self {
-        let ts = val.to_le();
+        let ts = val as u64;
 
         Timestamp {
-            time: ((ts as u64) >> 32) as u32,
+            time: (ts >> 32) as u32,
             increment: (ts & 0xFFFF_FFFF) as u32,
         }
     }
 }
 
+/// Converts a [`std::time::SystemTime`] into a [`Timestamp`] with an `increment` of 0.
+///
+/// Times before the Unix epoch convert to `Timestamp { time: 0, increment: 0 }`, and times too
+/// far in the future for a `u32` number of seconds saturate to [`u32::MAX`].
+impl From<std::time::SystemTime> for Timestamp {
+    fn from(st: std::time::SystemTime) -> Self {
+        let time = match st.duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => u32::try_from(d.as_secs()).unwrap_or(u32::MAX),
+            Err(_) => 0,
+        };
+        Timestamp { time, increment: 0 }
+    }
+}
+
 /// Represents a BSON regular expression value.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Regex {
     /// The regex pattern to match.
     pub pattern: String,
@@ -1065,7 +1775,7 @@ impl Display for Regex {
 }
 
 /// Represents a BSON code with scope value.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct JavaScriptCodeWithScope {
     /// The JavaScript code.
     pub code: String,
@@ -1081,7 +1791,7 @@ impl Display for JavaScriptCodeWithScope {
 }
 
 /// Represents a BSON binary value.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Binary {
     /// The subtype of the bytes.
     pub subtype: BinarySubtype,
@@ -1140,7 +1850,7 @@ impl Binary {
 }
 
 /// Represents a DBPointer. (Deprecated)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DbPointer {
     pub(crate) namespace: String,
     pub(crate) id: oid::ObjectId,
@@ -0,0 +1,115 @@
+//! A [`tokio_util::codec`] implementation for framing streams of BSON documents.
+//!
+//! BSON documents are self-delimiting: every document begins with its total length as a
+//! little-endian `i32`. [`BsonDocumentCodec`] uses that length prefix as the frame boundary, which
+//! allows a byte stream such as a `TcpStream` to be turned into a `Stream` and `Sink` of
+//! [`Document`]s via [`Framed`](tokio_util::codec::Framed).
+//!
+//! ```
+//! # tokio::runtime::Builder::new_current_thread().build()?.block_on(async {
+//! use bson::{codec::BsonDocumentCodec, doc};
+//! use futures::{SinkExt, StreamExt};
+//! use tokio_util::codec::Framed;
+//!
+//! let (client, server) = tokio::io::duplex(1024);
+//! let mut client = Framed::new(client, BsonDocumentCodec::new());
+//! let mut server = Framed::new(server, BsonDocumentCodec::new());
+//!
+//! client.send(doc! { "ping": 1 }).await?;
+//! assert_eq!(server.next().await.transpose()?, Some(doc! { "ping": 1 }));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! # })?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::convert::TryInto;
+
+use serde::de::Error as _;
+use tokio_util::{
+    bytes::BytesMut,
+    codec::{Decoder, Encoder},
+};
+
+use crate::{
+    de::{MAX_ENCODED_DOCUMENT_SIZE, MIN_BSON_DOCUMENT_SIZE},
+    Document,
+};
+
+/// A codec that decodes a byte stream into [`Document`]s and encodes [`Document`]s into a byte
+/// stream, using each document's length prefix as the frame delimiter.
+///
+/// Frames whose declared length is below the minimum document size or above
+/// [`MAX_ENCODED_DOCUMENT_SIZE`](crate::MAX_ENCODED_DOCUMENT_SIZE) are rejected with an error,
+/// since the stream can no longer be interpreted reliably. Documents larger than that limit are
+/// likewise rejected when encoding, with
+/// [`ser::Error::DocumentTooLarge`](crate::ser::Error::DocumentTooLarge).
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct BsonDocumentCodec;
+
+impl BsonDocumentCodec {
+    /// Creates a new `BsonDocumentCodec`.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Decoder for BsonDocumentCodec {
+    type Item = Document;
+    type Error = crate::de::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> crate::de::Result<Option<Document>> {
+        let prefix: [u8; 4] = match src.get(..4) {
+            // Unwrap safety: the slice is exactly 4 bytes long.
+            Some(prefix) => prefix.try_into().unwrap(),
+            None => return Ok(None),
+        };
+
+        let length = i32::from_le_bytes(prefix);
+        if !(MIN_BSON_DOCUMENT_SIZE..=MAX_ENCODED_DOCUMENT_SIZE).contains(&length) {
+            return Err(crate::de::Error::invalid_length(
+                length as usize,
+                &format!(
+                    "a document length between {} and {}",
+                    MIN_BSON_DOCUMENT_SIZE, MAX_ENCODED_DOCUMENT_SIZE
+                )
+                .as_str(),
+            ));
+        }
+
+        let length = length as usize;
+        if src.len() < length {
+            src.reserve(length - src.len());
+            return Ok(None);
+        }
+
+        let frame = src.split_to(length);
+        crate::decode_document_from_slice(&frame).map(Some)
+    }
+}
+
+impl Encoder<Document> for BsonDocumentCodec {
+    type Error = crate::ser::Error;
+
+    fn encode(&mut self, doc: Document, dst: &mut BytesMut) -> crate::ser::Result<()> {
+        self.encode(&doc, dst)
+    }
+}
+
+impl<'a> Encoder<&'a Document> for BsonDocumentCodec {
+    type Error = crate::ser::Error;
+
+    fn encode(&mut self, doc: &'a Document, dst: &mut BytesMut) -> crate::ser::Result<()> {
+        // Use the same limit as the decoder so that every frame written can be read back.
+        let actual = doc.byte_len();
+        if actual > crate::MAX_ENCODED_DOCUMENT_SIZE {
+            return Err(crate::ser::Error::DocumentTooLarge {
+                actual,
+                limit: crate::MAX_ENCODED_DOCUMENT_SIZE,
+            });
+        }
+
+        dst.extend_from_slice(&crate::encode_document_to_vec(doc)?);
+        Ok(())
+    }
+}
@@ -10,6 +10,11 @@ use std::{
 
 pub(crate) mod builder;
 pub use crate::datetime::builder::DateTimeBuilder;
+#[doc(inline)]
+pub use crate::serde_helpers::{
+    bson_datetime_as_millis as serde_as_millis,
+    bson_datetime_as_rfc3339_string as serde_rfc3339,
+};
 use time::format_description::well_known::Rfc3339;
 
 #[cfg(feature = "chrono-0_4")]
@@ -5,6 +5,13 @@ use serde::de::{self, Unexpected};
 use crate::Bson;
 
 /// Possible errors that can arise during decoding.
+///
+/// This enum is `#[non_exhaustive]`, so new variants may be added in minor releases; code that
+/// matches on it needs a wildcard arm. The variants describing specific, structured failures
+/// (`Io`, `InvalidUtf8String`, `UnrecognizedDocumentElementType`, `EndOfStream` and
+/// `UnknownField`) are stable to match on. `DeserializationError` covers every other error raised
+/// through [`serde::de::Error`], such as invalid types or lengths, and its message is not a stable
+/// format; use [`Error::path`] rather than parsing the message to locate the failing value.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum Error {
@@ -13,6 +20,11 @@ pub enum Error {
 
     /// A [`std::string::FromUtf8Error`](https://doc.rust-lang.org/std/string/struct.FromUtf8Error.html) encountered
     /// while decoding a UTF-8 String from the input data.
+    ///
+    /// This is returned for both keys and string values, whichever deserializer is used. The
+    /// offending bytes are available via
+    /// [`FromUtf8Error::as_bytes`](string::FromUtf8Error::as_bytes). The location of the string is
+    /// not recorded, so [`Error::path`] is empty for this variant.
     InvalidUtf8String(string::FromUtf8Error),
 
     /// While decoding a `Document` from bytes, an unexpected or unsupported element type was
@@ -35,7 +47,72 @@ pub enum Error {
     DeserializationError {
         /// A message describing the error.
         message: String,
+
+        /// The location of the value that could not be deserialized, starting from the top-level
+        /// document. Empty if the error occurred at the top level.
+        path: Vec<PathSegment>,
     },
+
+    /// A document contained a field that the type being deserialized does not expect. This is
+    /// only reported for types that reject unknown fields, e.g. via
+    /// `#[serde(deny_unknown_fields)]`; otherwise unknown fields are skipped.
+    #[non_exhaustive]
+    UnknownField {
+        /// The name of the unexpected field.
+        field: String,
+
+        /// The names of the fields the type expects.
+        expected: &'static [&'static str],
+
+        /// The location of the document containing the field, starting from the top-level
+        /// document. Empty if the field is in the top-level document.
+        path: Vec<PathSegment>,
+    },
+}
+
+/// A single step in the path from a top-level document to a nested value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PathSegment {
+    /// The value of the document field with the given key.
+    Key(String),
+
+    /// The array element at the given index.
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PathSegment::Key(key) => write!(fmt, ".{}", key),
+            PathSegment::Index(index) => write!(fmt, "[{}]", index),
+        }
+    }
+}
+
+impl Error {
+    /// The location of the value that caused this error, starting from the top-level document
+    /// (e.g. `[Key("users"), Index(2), Key("email")]` for `.users[2].email`).
+    ///
+    /// This is empty if the error occurred at the top level or if no location information is
+    /// available for this kind of error.
+    pub fn path(&self) -> &[PathSegment] {
+        match self {
+            Error::DeserializationError { path, .. } | Error::UnknownField { path, .. } => path,
+            _ => &[],
+        }
+    }
+
+    /// Records that this error occurred within the value at the given segment. Since errors
+    /// propagate from the innermost value outwards, each segment is prepended to the path.
+    pub(crate) fn with_path_segment(mut self, segment: impl FnOnce() -> PathSegment) -> Self {
+        match self {
+            Error::DeserializationError { ref mut path, .. }
+            | Error::UnknownField { ref mut path, .. } => path.insert(0, segment()),
+            _ => {}
+        }
+        self
+    }
 }
 
 impl From<io::Error> for Error {
@@ -64,7 +141,42 @@ impl fmt::Display for Error {
                 key, element_type
             ),
             Error::EndOfStream => fmt.write_str("end of stream"),
-            Error::DeserializationError { ref message } => message.fmt(fmt),
+            Error::DeserializationError {
+                ref message,
+                ref path,
+            } => {
+                for segment in path {
+                    segment.fmt(fmt)?;
+                }
+                if !path.is_empty() {
+                    fmt.write_str(": ")?;
+                }
+                message.fmt(fmt)
+            }
+            Error::UnknownField {
+                ref field,
+                expected,
+                ref path,
+            } => {
+                for segment in path {
+                    segment.fmt(fmt)?;
+                }
+                if !path.is_empty() {
+                    fmt.write_str(": ")?;
+                }
+                write!(fmt, "unknown field `{}`, ", field)?;
+                match expected {
+                    [] => fmt.write_str("there are no fields"),
+                    [only] => write!(fmt, "expected `{}`", only),
+                    [first, rest @ ..] => {
+                        write!(fmt, "expected one of `{}`", first)?;
+                        for name in rest {
+                            write!(fmt, ", `{}`", name)?;
+                        }
+                        Ok(())
+                    }
+                }
+            }
         }
     }
 }
@@ -83,6 +195,15 @@ impl de::Error for Error {
     fn custom<T: Display>(msg: T) -> Error {
         Error::DeserializationError {
             message: msg.to_string(),
+            path: Vec::new(),
+        }
+    }
+
+    fn unknown_field(field: &str, expected: &'static [&'static str]) -> Error {
+        Error::UnknownField {
+            field: field.to_string(),
+            expected,
+            path: Vec::new(),
         }
     }
 }
@@ -92,7 +213,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 impl Bson {
     /// Method for converting a given `Bson` value to a `serde::de::Unexpected` for error reporting.
-    pub(crate) fn as_unexpected(&self) -> Unexpected {
+    pub(crate) fn as_unexpected(&self) -> Unexpected<'_> {
         match self {
             Bson::Array(_) => Unexpected::Seq,
             Bson::Binary(b) => Unexpected::Bytes(b.bytes.as_slice()),
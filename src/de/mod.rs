@@ -26,7 +26,7 @@ mod raw;
 mod serde;
 
 pub use self::{
-    error::{Error, Result},
+    error::{Error, PathSegment, Result},
     serde::{Deserializer, DeserializerOptions},
 };
 
@@ -42,7 +42,7 @@ use crate::{
 };
 
 use ::serde::{
-    de::{DeserializeOwned, Error as _, Unexpected},
+    de::{DeserializeOwned, DeserializeSeed, Error as _, Unexpected},
     Deserialize,
 };
 
@@ -51,7 +51,8 @@ pub(crate) use self::serde::{convert_unsigned_to_signed_raw, BsonVisitor};
 #[cfg(test)]
 pub(crate) use self::raw::Deserializer as RawDeserializer;
 
-pub(crate) const MAX_BSON_SIZE: i32 = 16 * 1024 * 1024;
+pub(crate) const MAX_BSON_SIZE: i32 = crate::MAX_BSON_SIZE as i32;
+pub(crate) const MAX_ENCODED_DOCUMENT_SIZE: i32 = crate::MAX_ENCODED_DOCUMENT_SIZE as i32;
 pub(crate) const MIN_BSON_DOCUMENT_SIZE: i32 = 4 + 1; // 4 bytes for length, one byte for null terminator
 pub(crate) const MIN_BSON_STRING_SIZE: i32 = 4 + 1; // 4 bytes for length, one byte for null terminator
 pub(crate) const MIN_CODE_WITH_SCOPE_SIZE: i32 = 4 + MIN_BSON_STRING_SIZE + MIN_BSON_DOCUMENT_SIZE;
@@ -107,14 +108,13 @@ pub(crate) fn read_string<R: Read + ?Sized>(reader: &mut R, utf8_lossy: bool) ->
         ));
     }
 
+    // the buffer grows as bytes are read, rather than being sized by the untrusted length prefix.
+    let mut buf = Vec::new();
+    reader.take(len as u64 - 1).read_to_end(&mut buf)?;
     let s = if utf8_lossy {
-        let mut buf = Vec::with_capacity(len as usize - 1);
-        reader.take(len as u64 - 1).read_to_end(&mut buf)?;
         String::from_utf8_lossy(&buf).to_string()
     } else {
-        let mut s = String::with_capacity(len as usize - 1);
-        reader.take(len as u64 - 1).read_to_string(&mut s)?;
-        s
+        String::from_utf8(buf)?
     };
 
     // read the null terminator
@@ -195,12 +195,12 @@ fn deserialize_array<R: Read + ?Sized>(reader: &mut R, utf8_lossy: bool) -> Resu
     let mut arr = Array::new();
     let length = read_i32(reader)?;
 
-    if !(MIN_BSON_DOCUMENT_SIZE..=MAX_BSON_SIZE).contains(&length) {
+    if !(MIN_BSON_DOCUMENT_SIZE..=MAX_ENCODED_DOCUMENT_SIZE).contains(&length) {
         return Err(Error::invalid_length(
             length as usize,
             &format!(
                 "array length must be between {} and {}",
-                MIN_BSON_DOCUMENT_SIZE, MAX_BSON_SIZE
+                MIN_BSON_DOCUMENT_SIZE, MAX_ENCODED_DOCUMENT_SIZE
             )
             .as_str(),
         ));
@@ -438,6 +438,14 @@ impl JavaScriptCodeWithScope {
 /// the format is human readable or not. To deserialize from [`Bson`] with a deserializer that
 /// presents itself as not human readable, use [`from_bson_with_options`] with
 /// [`DeserializerOptions::human_readable`] set to false.
+///
+/// ```
+/// use bson::bson;
+///
+/// let pair: (i32, String) = bson::from_bson(bson!([1, "two"]))?;
+/// assert_eq!(pair, (1, "two".to_string()));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn from_bson<T>(bson: Bson) -> Result<T>
 where
     T: DeserializeOwned,
@@ -478,6 +486,21 @@ where
 /// the format is human readable or not. To deserialize from [`Document`] with a deserializer that
 /// presents itself as not human readable, use [`from_document_with_options`] with
 /// [`DeserializerOptions::human_readable`] set to false.
+///
+/// ```
+/// use bson::doc;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let point: Point = bson::from_document(doc! { "x": 1, "y": 2 })?;
+/// assert_eq!(point, Point { x: 1, y: 2 });
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn from_document<T>(doc: Document) -> Result<T>
 where
     T: DeserializeOwned,
@@ -514,16 +537,261 @@ fn reader_to_vec<R: Read>(mut reader: R) -> Result<Vec<u8>> {
 
     if length < MIN_BSON_DOCUMENT_SIZE {
         return Err(Error::custom("document size too small"));
+    } else if length > MAX_ENCODED_DOCUMENT_SIZE {
+        return Err(Error::invalid_length(
+            length as usize,
+            &format!(
+                "document length must be at most {}",
+                MAX_ENCODED_DOCUMENT_SIZE
+            )
+            .as_str(),
+        ));
     }
 
-    let mut bytes = Vec::with_capacity(length as usize);
+    // The buffer grows as data is actually read, so a corrupt prefix can't cause the claimed size
+    // to be allocated up front.
+    let mut bytes = Vec::new();
     write_i32(&mut bytes, length).map_err(Error::custom)?;
 
     reader.take(length as u64 - 4).read_to_end(&mut bytes)?;
     Ok(bytes)
 }
 
+/// Decode a single [`Document`] from an I/O stream of BSON.
+///
+/// The 4-byte length prefix is read and validated first, and then exactly that many bytes (minus
+/// the prefix) are read from the stream before parsing, so the reader is left positioned at the
+/// start of whatever follows the document.
+///
+/// ```
+/// use bson::{doc, decode_document, encode_document};
+///
+/// let mut bytes = Vec::new();
+/// encode_document(&mut bytes, &doc! { "x": 1 })?;
+/// encode_document(&mut bytes, &doc! { "y": 2 })?;
+///
+/// let mut reader = bytes.as_slice();
+/// assert_eq!(decode_document(&mut reader)?, doc! { "x": 1 });
+/// assert_eq!(decode_document(&mut reader)?, doc! { "y": 2 });
+/// assert!(reader.is_empty());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn decode_document<R: Read + ?Sized>(reader: &mut R) -> Result<Document> {
+    Document::from_reader(reader)
+}
+
+/// Decode a single [`Document`] from a slice of BSON bytes.
+///
+/// Unlike [`decode_document`], the slice must contain exactly one document; trailing bytes are
+/// reported as an error.
+///
+/// ```
+/// use bson::{decode_document_from_slice, doc};
+///
+/// let mut bytes = bson::to_vec(&doc! { "x": 1 })?;
+/// assert_eq!(decode_document_from_slice(&bytes)?, doc! { "x": 1 });
+///
+/// bytes.push(0);
+/// assert!(decode_document_from_slice(&bytes).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn decode_document_from_slice(bytes: &[u8]) -> Result<Document> {
+    // Check the length prefix against the input first, so that a corrupt prefix can't cause a large
+    // buffer to be allocated for a document that isn't there.
+    if let Some(prefix) = bytes.get(..4) {
+        let length = i32::from_le_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]);
+        if length > MAX_ENCODED_DOCUMENT_SIZE {
+            return Err(Error::invalid_length(
+                length as usize,
+                &format!(
+                    "document length must be at most {}",
+                    MAX_ENCODED_DOCUMENT_SIZE
+                )
+                .as_str(),
+            ));
+        }
+        if length > 0 && length as usize > bytes.len() {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
+    }
+
+    let mut reader = bytes;
+    let doc = decode_document(&mut reader)?;
+    if !reader.is_empty() {
+        return Err(Error::invalid_length(
+            bytes.len(),
+            &"a slice containing exactly one document",
+        ));
+    }
+    Ok(doc)
+}
+
+/// Decode a single [`Document`] from a [`bytes::Bytes`] buffer.
+///
+/// Like [`decode_document_from_slice`], the buffer must contain exactly one document.
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub fn decode_document_from_bytes(bytes: bytes::Bytes) -> Result<Document> {
+    decode_document_from_slice(&bytes)
+}
+
+/// Decode a standalone BSON array from a slice of BSON bytes, as produced by
+/// [`crate::encode_array_to_vec`].
+///
+/// The keys of the array's elements are not checked; values are returned in the order they
+/// appear. As with [`decode_document_from_slice`], trailing bytes are reported as an error.
+pub fn decode_array_from_slice(bytes: &[u8]) -> Result<Array> {
+    let mut reader = bytes;
+    let array = deserialize_array(&mut reader, false)?;
+    if !reader.is_empty() {
+        return Err(Error::invalid_length(
+            bytes.len(),
+            &"a slice containing exactly one array",
+        ));
+    }
+    Ok(array)
+}
+
+/// Decode a single [`Document`] from an asynchronous stream of BSON.
+///
+/// The 4-byte length prefix is read first and checked against
+/// [`crate::MAX_ENCODED_DOCUMENT_SIZE`], the largest document the encoders will write, and then
+/// the rest of the document is read with [`read_exact`](tokio::io::AsyncReadExt::read_exact)
+/// before parsing, so the executor is never blocked and the reader is left positioned at the start
+/// of whatever follows the document.
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub async fn decode_document_async<R>(reader: &mut R) -> Result<Document>
+where
+    R: tokio::io::AsyncRead + Unpin + ?Sized,
+{
+    use tokio::io::AsyncReadExt;
+
+    let mut prefix = [0u8; 4];
+    reader.read_exact(&mut prefix).await?;
+    let length = i32::from_le_bytes(prefix);
+    if !(MIN_BSON_DOCUMENT_SIZE..=MAX_ENCODED_DOCUMENT_SIZE).contains(&length) {
+        return Err(Error::invalid_length(
+            length as usize,
+            &format!(
+                "document length must be between {} and {}",
+                MIN_BSON_DOCUMENT_SIZE, MAX_ENCODED_DOCUMENT_SIZE
+            )
+            .as_str(),
+        ));
+    }
+
+    let mut bytes = vec![0u8; length as usize];
+    bytes[..4].copy_from_slice(&prefix);
+    reader.read_exact(&mut bytes[4..]).await?;
+    decode_document_from_slice(&bytes)
+}
+
+/// An iterator over the [`Document`]s in a stream of concatenated BSON documents, such as a
+/// `mongodump` output file.
+///
+/// Each call to [`Iterator::next`] decodes one document using its length prefix. Iteration ends
+/// with `None` if the stream is exhausted at a document boundary; running out of input part way
+/// through a document is reported as an error. After an error is returned, the position in the
+/// stream is unknown, so no further documents are produced.
+///
+/// ```
+/// use bson::{doc, encode_document, DocumentReader};
+///
+/// let mut bytes = Vec::new();
+/// encode_document(&mut bytes, &doc! { "x": 1 })?;
+/// encode_document(&mut bytes, &doc! { "x": 2 })?;
+///
+/// let docs = DocumentReader::new(bytes.as_slice()).collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(docs, vec![doc! { "x": 1 }, doc! { "x": 2 }]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct DocumentReader<R> {
+    reader: R,
+    done: bool,
+}
+
+impl<R: Read> DocumentReader<R> {
+    /// Creates a new `DocumentReader` that reads documents from the provided stream.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            done: false,
+        }
+    }
+
+    /// Consumes this `DocumentReader`, returning the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the length prefix of the next document, returning `None` if the stream ended before
+    /// any of it could be read.
+    fn read_length_prefix(&mut self) -> Result<Option<[u8; 4]>> {
+        let mut prefix = [0u8; 4];
+        let mut filled = 0;
+        while filled < prefix.len() {
+            match self.reader.read(&mut prefix[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => return Err(Error::EndOfStream),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(Some(prefix))
+    }
+}
+
+impl<R: Read> Iterator for DocumentReader<R> {
+    type Item = Result<Document>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = match self.read_length_prefix() {
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Ok(Some(prefix)) => decode_document(&mut prefix.as_ref().chain(&mut self.reader)),
+            Err(e) => Err(e),
+        };
+
+        if result.is_err() {
+            self.done = true;
+        }
+        Some(result)
+    }
+}
+
+impl<R: Read> std::iter::FusedIterator for DocumentReader<R> {}
+
 /// Deserialize an instance of type `T` from an I/O stream of BSON.
+///
+/// Only the bytes of a single document, as given by its length prefix, are read from `reader`,
+/// so consecutive documents can be read from the same stream by passing `&mut reader`. Those
+/// bytes are buffered once and then deserialized in the same way as [`from_slice`], without
+/// building an intermediate [`Document`].
+///
+/// ```
+/// use bson::doc;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let bytes = bson::to_vec(&doc! { "x": 1, "y": 2 })?;
+/// let point: Point = bson::from_reader(std::io::Cursor::new(bytes))?;
+/// assert_eq!(point, Point { x: 1, y: 2 });
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn from_reader<R, T>(reader: R) -> Result<T>
 where
     T: DeserializeOwned,
@@ -549,6 +817,25 @@ where
 }
 
 /// Deserialize an instance of type `T` from a slice of BSON bytes.
+///
+/// The bytes are parsed directly as the visitor requests each value, without first building an
+/// intermediate [`Document`], so this is generally faster than [`crate::from_document`] on a
+/// decoded document. Strings and binary data can be borrowed straight from `bytes`:
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Cat<'a> {
+///     name: &'a str,
+///     lives: i32,
+/// }
+///
+/// let bytes = bson::to_vec(&bson::doc! { "name": "Garfield", "lives": 9 })?;
+/// let cat: Cat = bson::from_slice(&bytes)?;
+/// assert_eq!(cat, Cat { name: "Garfield", lives: 9 });
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn from_slice<'de, T>(bytes: &'de [u8]) -> Result<T>
 where
     T: Deserialize<'de>,
@@ -557,6 +844,44 @@ where
     T::deserialize(&mut deserializer)
 }
 
+/// Deserialize a value from a slice of BSON bytes using the provided [`DeserializeSeed`], which
+/// allows state (e.g. an arena or a schema) to be threaded through deserialization.
+///
+/// ```
+/// use serde::{
+///     de::{DeserializeSeed, Deserializer},
+///     Deserialize,
+/// };
+///
+/// /// Deserializes a document's `values` array, scaling each one by a factor known at runtime.
+/// struct Scaled(i32);
+///
+/// impl<'de> DeserializeSeed<'de> for Scaled {
+///     type Value = Vec<i32>;
+///
+///     fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Vec<i32>, D::Error> {
+///         #[derive(Deserialize)]
+///         struct Values {
+///             values: Vec<i32>,
+///         }
+///
+///         let Values { values } = Values::deserialize(deserializer)?;
+///         Ok(values.into_iter().map(|v| v * self.0).collect())
+///     }
+/// }
+///
+/// let bytes = bson::to_vec(&bson::doc! { "values": [1, 2, 3] })?;
+/// assert_eq!(bson::from_slice_seed(&bytes, Scaled(10))?, vec![10, 20, 30]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn from_slice_seed<'de, S>(bytes: &'de [u8], seed: S) -> Result<S::Value>
+where
+    S: DeserializeSeed<'de>,
+{
+    let mut deserializer = raw::Deserializer::new(bytes, false);
+    seed.deserialize(&mut deserializer)
+}
+
 /// Deserialize an instance of type `T` from a slice of BSON bytes, replacing any invalid UTF-8
 /// sequences with the Unicode replacement character.
 ///
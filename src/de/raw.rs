@@ -34,6 +34,7 @@ use super::{
     read_u8,
     DeserializerHint,
     Error,
+    PathSegment,
     Result,
     MAX_BSON_SIZE,
     MIN_CODE_WITH_SCOPE_SIZE,
@@ -179,6 +180,8 @@ impl<'de> Deserializer<'de> {
         let out = f(DocumentAccess {
             root_deserializer: self,
             length_remaining: &mut length_remaining,
+            key_start: 0,
+            index: 0,
         });
 
         if out.is_ok() {
@@ -383,7 +386,7 @@ impl<'de> Deserializer<'de> {
     }
 }
 
-impl<'de, 'a> serde::de::Deserializer<'de> for &'a mut Deserializer<'de> {
+impl<'de> serde::de::Deserializer<'de> for &mut Deserializer<'de> {
     type Error = Error;
 
     #[inline]
@@ -483,6 +486,13 @@ impl<'de, 'a> serde::de::Deserializer<'de> for &'a mut Deserializer<'de> {
 struct DocumentAccess<'d, 'de> {
     root_deserializer: &'d mut Deserializer<'de>,
     length_remaining: &'d mut i32,
+
+    /// The position in the input of the most recently read key. The key is only decoded again if
+    /// it is needed to report where an error occurred.
+    key_start: usize,
+
+    /// The number of elements read so far when accessing the document as a sequence.
+    index: usize,
 }
 
 impl<'d, 'de> DocumentAccess<'d, 'de> {
@@ -541,6 +551,7 @@ impl<'d, 'de> serde::de::MapAccess<'de> for DocumentAccess<'d, 'de> {
             return Ok(None);
         }
 
+        self.key_start = self.root_deserializer.bytes.bytes_read();
         self.read(|s| {
             seed.deserialize(DocumentKeyDeserializer {
                 root_deserializer: &mut *s.root_deserializer,
@@ -553,7 +564,11 @@ impl<'d, 'de> serde::de::MapAccess<'de> for DocumentAccess<'d, 'de> {
     where
         V: serde::de::DeserializeSeed<'de>,
     {
-        self.read_next_value(seed)
+        self.read_next_value(seed).map_err(|e| {
+            e.with_path_segment(|| {
+                PathSegment::Key(self.root_deserializer.bytes.cstr_at(self.key_start))
+            })
+        })
     }
 }
 
@@ -568,7 +583,11 @@ impl<'d, 'de> serde::de::SeqAccess<'de> for DocumentAccess<'d, 'de> {
             return Ok(None);
         }
         let _index = self.read(|s| s.root_deserializer.deserialize_cstr())?;
-        self.read_next_value(seed).map(Some)
+        let index = self.index;
+        self.index += 1;
+        self.read_next_value(seed)
+            .map(Some)
+            .map_err(|e| e.with_path_segment(|| PathSegment::Index(index)))
     }
 }
 
@@ -990,10 +1009,10 @@ impl TimestampDeserializer {
     }
 }
 
-impl<'de, 'a> serde::de::Deserializer<'de> for &'a mut TimestampDeserializer {
+impl<'de> serde::de::Deserializer<'de> for &mut TimestampDeserializer {
     type Error = Error;
 
-    fn deserialize_any<V>(mut self, visitor: V) -> Result<V::Value>
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
@@ -1090,10 +1109,10 @@ impl DateTimeDeserializer {
     }
 }
 
-impl<'de, 'a> serde::de::Deserializer<'de> for &'a mut DateTimeDeserializer {
+impl<'de> serde::de::Deserializer<'de> for &mut DateTimeDeserializer {
     type Error = Error;
 
-    fn deserialize_any<V>(mut self, visitor: V) -> Result<V::Value>
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
@@ -1191,10 +1210,10 @@ impl<'a> BinaryDeserializer<'a> {
     }
 }
 
-impl<'de, 'a> serde::de::Deserializer<'de> for &'a mut BinaryDeserializer<'de> {
+impl<'de> serde::de::Deserializer<'de> for &mut BinaryDeserializer<'de> {
     type Error = Error;
 
-    fn deserialize_any<V>(mut self, visitor: V) -> Result<V::Value>
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
@@ -1328,7 +1347,7 @@ impl<'de, 'a> CodeWithScopeDeserializer<'de, 'a> {
 impl<'de, 'a, 'b> serde::de::Deserializer<'de> for &'b mut CodeWithScopeDeserializer<'de, 'a> {
     type Error = Error;
 
-    fn deserialize_any<V>(mut self, visitor: V) -> Result<V::Value>
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
@@ -1445,7 +1464,7 @@ impl<'de, 'a> DbPointerDeserializer<'de, 'a> {
 impl<'de, 'a, 'b> serde::de::Deserializer<'de> for &'b mut DbPointerDeserializer<'de, 'a> {
     type Error = Error;
 
-    fn deserialize_any<V>(mut self, visitor: V) -> Result<V::Value>
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
@@ -1741,6 +1760,14 @@ impl<'a> BsonBuf<'a> {
         self.bytes.len() - self.bytes_read()
     }
 
+    /// Lossily decode the null-terminated string starting at the given index, without advancing
+    /// the buffer. Used to recover keys for error reporting.
+    fn cstr_at(&self, start: usize) -> String {
+        let bytes = self.bytes.get(start..).unwrap_or_default();
+        let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[..end]).into_owned()
+    }
+
     /// Verify the index has not run out of bounds.
     fn index_check(&self) -> std::io::Result<()> {
         if self.index >= self.bytes.len() {
@@ -1757,7 +1784,11 @@ impl<'a> BsonBuf<'a> {
         let s = if utf8_lossy_override.unwrap_or(self.utf8_lossy) {
             String::from_utf8_lossy(bytes)
         } else {
-            Cow::Borrowed(std::str::from_utf8(bytes).map_err(Error::custom)?)
+            match std::str::from_utf8(bytes) {
+                Ok(s) => Cow::Borrowed(s),
+                // convert an owned copy so the error matches the one `from_reader` reports
+                Err(_) => return Err(String::from_utf8(bytes.to_vec()).unwrap_err().into()),
+            }
         };
 
         // consume the null byte
@@ -1798,8 +1829,13 @@ impl<'a> BsonBuf<'a> {
             ));
         }
 
-        self.index += (len - 1) as usize;
-        self.index_check()?;
+        // Check the bounds before advancing so that a bogus length doesn't leave the index pointing
+        // far past the end of the buffer.
+        let end = start + (len - 1) as usize;
+        if end >= self.bytes.len() {
+            return Err(Error::Io(Arc::new(ErrorKind::UnexpectedEof.into())));
+        }
+        self.index = end;
 
         Ok(start)
     }
@@ -31,7 +31,7 @@ use crate::{
     Decimal128,
 };
 
-use super::{raw::Decimal128Access, DeserializerHint};
+use super::{raw::Decimal128Access, DeserializerHint, PathSegment};
 
 pub(crate) struct BsonVisitor;
 
@@ -116,6 +116,16 @@ impl<'de> Deserialize<'de> for Document {
     }
 }
 
+/// `Bson` deserializes from any self-describing format, human-readable or not. Strings,
+/// booleans, floats and nulls map to the corresponding `Bson` variants, signed 64-bit integers
+/// map to [`Bson::Int64`], and unsigned integers map to [`Bson::Int32`] or [`Bson::Int64`]
+/// depending on their magnitude. Maps in extended JSON form (e.g. `{ "$oid": "..." }`) are
+/// converted to the BSON type they describe.
+///
+/// The same visitor is used regardless of
+/// [`is_human_readable`](de::Deserializer::is_human_readable), since this crate's own
+/// [`Deserializer`] reports itself as human-readable by default and must still round-trip every
+/// BSON type exactly.
 impl<'de> Deserialize<'de> for Bson {
     #[inline]
     fn deserialize<D>(deserializer: D) -> Result<Bson, D::Error>
@@ -205,6 +215,14 @@ impl<'de> Visitor<'de> for BsonVisitor {
         convert_unsigned_to_signed(value)
     }
 
+    #[inline]
+    fn visit_f32<E>(self, value: f32) -> Result<Bson, E> {
+        // BSON has no single-precision type. Every f32 is exactly representable as an f64, so the
+        // promotion is lossless, but the resulting double prints with the f32's rounding error
+        // (e.g. 0.1f32 becomes 0.10000000149011612).
+        Ok(Bson::Double(value as f64))
+    }
+
     #[inline]
     fn visit_f64<E>(self, value: f64) -> Result<Bson, E> {
         Ok(Bson::Double(value))
@@ -223,6 +241,14 @@ impl<'de> Visitor<'de> for BsonVisitor {
         Ok(Bson::String(value))
     }
 
+    #[inline]
+    fn visit_char<E>(self, value: char) -> Result<Bson, E>
+    where
+        E: de::Error,
+    {
+        Ok(Bson::String(value.to_string()))
+    }
+
     #[inline]
     fn visit_none<E>(self) -> Result<Bson, E> {
         Ok(Bson::Null)
@@ -357,13 +383,13 @@ impl<'de> Visitor<'de> for BsonVisitor {
                 "$numberDouble" => {
                     let string: String = visitor.next_value()?;
                     let val = match string.as_str() {
-                        "Infinity" => Bson::Double(std::f64::INFINITY),
-                        "-Infinity" => Bson::Double(std::f64::NEG_INFINITY),
-                        "NaN" => Bson::Double(std::f64::NAN),
-                        _ => Bson::Int64(string.parse().map_err(|_| {
+                        "Infinity" => Bson::Double(f64::INFINITY),
+                        "-Infinity" => Bson::Double(f64::NEG_INFINITY),
+                        "NaN" => Bson::Double(f64::NAN),
+                        _ => Bson::Double(string.parse().map_err(|_| {
                             V::Error::invalid_value(
                                 Unexpected::Str(&string),
-                                &"64-bit signed integer as a string",
+                                &"64-bit floating point number as a string",
                             )
                         })?),
                     };
@@ -465,10 +491,15 @@ impl<'de> Visitor<'de> for BsonVisitor {
                 }
 
                 "$numberDecimal" => {
-                    return Err(Error::custom(
-                        "deserializing decimal128 values from strings is not currently supported"
-                            .to_string(),
-                    ));
+                    let string: String = visitor.next_value()?;
+                    return Ok(Bson::Decimal128(Decimal128::parse_str(&string).map_err(
+                        |_| {
+                            V::Error::invalid_value(
+                                Unexpected::Str(&string),
+                                &"128-bit decimal as a string",
+                            )
+                        },
+                    )?));
                 }
 
                 "$numberDecimalBytes" => {
@@ -585,6 +616,7 @@ impl DeserializerOptions {
 }
 
 /// Builder used to construct a [`DeserializerOptions`].
+#[derive(Clone, Debug)]
 pub struct DeserializerOptionsBuilder {
     options: DeserializerOptions,
 }
@@ -604,6 +636,21 @@ impl DeserializerOptionsBuilder {
 
 impl Deserializer {
     /// Construct a new [`Deserializer`] using the default options.
+    ///
+    /// ```
+    /// use bson::{bson, Deserializer};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Debug, Deserialize, PartialEq)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let deserializer = Deserializer::new(bson!({ "x": 1, "y": 2 }));
+    /// assert_eq!(Point::deserialize(deserializer)?, Point { x: 1, y: 2 });
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn new(value: Bson) -> Deserializer {
         Deserializer::new_with_options(value, Default::default())
     }
@@ -616,6 +663,17 @@ impl Deserializer {
         }
     }
 
+    /// Deserialize the wrapped value using the provided [`DeserializeSeed`], which allows state
+    /// (e.g. an arena or a schema) to be threaded through deserialization.
+    ///
+    /// This is equivalent to `seed.deserialize(deserializer)`.
+    pub fn deserialize_seed<'de, S>(self, seed: S) -> crate::de::Result<S::Value>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
     fn deserialize_next<'de, V>(
         mut self,
         visitor: V,
@@ -646,14 +704,7 @@ impl Deserializer {
         match value {
             Bson::Double(v) => visitor.visit_f64(v),
             Bson::String(v) => visitor.visit_string(v),
-            Bson::Array(v) => {
-                let len = v.len();
-                visitor.visit_seq(SeqDeserializer {
-                    iter: v.into_iter(),
-                    options: self.options,
-                    len,
-                })
-            }
+            Bson::Array(v) => SeqDeserializer::new(v, self.options).visit(visitor),
             Bson::Document(v) => visitor.visit_map(MapDeserializer::new(v, self.options)),
             Bson::Boolean(v) => visitor.visit_bool(v),
             Bson::Null => visitor.visit_unit(),
@@ -772,7 +823,7 @@ impl<'de> de::Deserializer<'de> for Deserializer {
             Some(v) => {
                 return Err(crate::de::Error::invalid_type(
                     v.as_unexpected(),
-                    &"expected an enum",
+                    &"an enum",
                 ));
             }
             None => {
@@ -914,29 +965,27 @@ impl<'de> VariantAccess<'de> for VariantDeserializer {
     where
         T: DeserializeSeed<'de>,
     {
-        let dec = Deserializer::new_with_options(
-            self.val.take().ok_or(crate::de::Error::EndOfStream)?,
-            self.options,
-        );
-        seed.deserialize(dec)
+        let val = self.val.take().ok_or_else(|| {
+            crate::de::Error::invalid_type(Unexpected::UnitVariant, &"newtype variant")
+        })?;
+        seed.deserialize(Deserializer::new_with_options(val, self.options))
     }
 
     fn tuple_variant<V>(mut self, _len: usize, visitor: V) -> crate::de::Result<V::Value>
     where
         V: Visitor<'de>,
     {
-        match self.val.take().ok_or(crate::de::Error::EndOfStream)? {
+        let val = self.val.take().ok_or_else(|| {
+            crate::de::Error::invalid_type(Unexpected::UnitVariant, &"tuple variant")
+        })?;
+        match val {
             Bson::Array(fields) => {
-                let de = SeqDeserializer {
-                    len: fields.len(),
-                    iter: fields.into_iter(),
-                    options: self.options,
-                };
+                let de = SeqDeserializer::new(fields, self.options);
                 de.deserialize_any(visitor)
             }
             other => Err(crate::de::Error::invalid_type(
                 other.as_unexpected(),
-                &"expected a tuple",
+                &"an array of tuple variant fields",
             )),
         }
     }
@@ -949,19 +998,17 @@ impl<'de> VariantAccess<'de> for VariantDeserializer {
     where
         V: Visitor<'de>,
     {
-        match self.val.take().ok_or(crate::de::Error::EndOfStream)? {
+        let val = self.val.take().ok_or_else(|| {
+            crate::de::Error::invalid_type(Unexpected::UnitVariant, &"struct variant")
+        })?;
+        match val {
             Bson::Document(fields) => {
-                let de = MapDeserializer {
-                    len: fields.len(),
-                    iter: fields.into_iter(),
-                    value: None,
-                    options: self.options,
-                };
+                let de = MapDeserializer::new(fields, self.options);
                 de.deserialize_any(visitor)
             }
             ref other => Err(crate::de::Error::invalid_type(
                 other.as_unexpected(),
-                &"expected a struct",
+                &"a document of struct variant fields",
             )),
         }
     }
@@ -970,9 +1017,37 @@ impl<'de> VariantAccess<'de> for VariantDeserializer {
 struct SeqDeserializer {
     iter: vec::IntoIter<Bson>,
     len: usize,
+    index: usize,
     options: DeserializerOptions,
 }
 
+impl SeqDeserializer {
+    fn new(array: Vec<Bson>, options: DeserializerOptions) -> Self {
+        SeqDeserializer {
+            len: array.len(),
+            iter: array.into_iter(),
+            index: 0,
+            options,
+        }
+    }
+
+    /// Visit the array's elements, returning an error if the visitor stopped before consuming all
+    /// of them (e.g. a two-element tuple receiving a three-element array).
+    fn visit<'de, V>(mut self, visitor: V) -> crate::de::Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let value = visitor.visit_seq(&mut self)?;
+        if self.len != 0 {
+            return Err(crate::de::Error::invalid_length(
+                self.index + self.len,
+                &format!("{} elements in array", self.index).as_str(),
+            ));
+        }
+        Ok(value)
+    }
+}
+
 impl<'de> de::Deserializer<'de> for SeqDeserializer {
     type Error = crate::de::Error;
 
@@ -984,7 +1059,7 @@ impl<'de> de::Deserializer<'de> for SeqDeserializer {
         if self.len == 0 {
             visitor.visit_unit()
         } else {
-            visitor.visit_seq(self)
+            self.visit(visitor)
         }
     }
 
@@ -1031,10 +1106,12 @@ impl<'de> SeqAccess<'de> for SeqDeserializer {
             None => Ok(None),
             Some(value) => {
                 self.len -= 1;
+                let index = self.index;
+                self.index += 1;
                 let de = Deserializer::new_with_options(value, self.options.clone());
                 match seed.deserialize(de) {
                     Ok(value) => Ok(Some(value)),
-                    Err(err) => Err(err),
+                    Err(err) => Err(err.with_path_segment(|| PathSegment::Index(index))),
                 }
             }
         }
@@ -1048,6 +1125,8 @@ impl<'de> SeqAccess<'de> for SeqDeserializer {
 pub(crate) struct MapDeserializer {
     pub(crate) iter: IntoIter,
     pub(crate) value: Option<Bson>,
+    /// The key of `value`, used to report where errors occurred.
+    pub(crate) key: Option<String>,
     pub(crate) len: usize,
     pub(crate) options: DeserializerOptions,
 }
@@ -1059,6 +1138,7 @@ impl MapDeserializer {
             iter: doc.into_iter(),
             len,
             value: None,
+            key: None,
             options: options.into().unwrap_or_default(),
         }
     }
@@ -1075,6 +1155,7 @@ impl<'de> MapAccess<'de> for MapDeserializer {
             Some((key, value)) => {
                 self.len -= 1;
                 self.value = Some(value);
+                self.key = Some(key.clone());
 
                 let de = Deserializer::new_with_options(Bson::String(key), self.options.clone());
                 match seed.deserialize(de) {
@@ -1092,7 +1173,10 @@ impl<'de> MapAccess<'de> for MapDeserializer {
     {
         let value = self.value.take().ok_or(crate::de::Error::EndOfStream)?;
         let de = Deserializer::new_with_options(value, self.options.clone());
-        seed.deserialize(de)
+        seed.deserialize(de).map_err(|e| {
+            let key = self.key.take().unwrap_or_default();
+            e.with_path_segment(|| PathSegment::Key(key))
+        })
     }
 
     fn size_hint(&self) -> Option<usize> {
@@ -1,12 +1,69 @@
 //! [BSON Decimal128](https://github.com/mongodb/specifications/blob/master/source/bson-decimal128/decimal128.rst) data type representation
 
-use std::{convert::TryInto, fmt};
+use std::{convert::TryInto, error, fmt, str::FromStr};
+
+const EXPONENT_BIAS: i32 = 6176;
+const MAX_BIASED_EXPONENT: i32 = 12287;
+const MAX_DIGITS: usize = 34;
+const MAX_COEFFICIENT: u128 = 10u128.pow(MAX_DIGITS as u32) - 1;
+
+/// Errors that can occur when parsing a [`Decimal128`] from a string.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The provided string is not a valid decimal number, `Infinity` or `NaN`.
+    #[non_exhaustive]
+    InvalidDecimalString { input: String },
+
+    /// The provided string is a valid decimal number, but it has more significant digits or a
+    /// larger exponent than a [`Decimal128`] can hold without rounding.
+    #[non_exhaustive]
+    InexactValue { input: String },
+}
+
+/// Alias for `Result<T, decimal128::Error>`.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidDecimalString { input } => {
+                write!(fmt, "\"{}\" is not a valid decimal128 string", input)
+            }
+            Error::InexactValue { input } => {
+                write!(
+                    fmt,
+                    "\"{}\" cannot be represented exactly as a decimal128",
+                    input
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
 
 /// Struct representing a BSON Decimal128 type.
 ///
-/// Currently, this type can only be used to round-trip through BSON. See
-/// [RUST-36](https://jira.mongodb.org/browse/RUST-36) to track the progress towards a complete implementation.
-#[derive(Copy, Clone, PartialEq)]
+/// Values can be converted to and from the string representation described in the
+/// [Decimal128 specification](https://github.com/mongodb/specifications/blob/master/source/bson-decimal128/decimal128.rst)
+/// via [`Decimal128::parse_str`] (or [`str::parse`]) and [`Display`](fmt::Display), but no
+/// arithmetic is provided.
+///
+/// Equality compares the underlying bits, so values that are numerically equal but have a
+/// different exponent (e.g. `1.0` and `1.00`) are not equal.
+///
+/// ```
+/// use bson::Decimal128;
+///
+/// let d: Decimal128 = "1.50".parse()?;
+/// assert_eq!(d.to_string(), "1.50");
+/// assert_ne!(d, "1.5".parse()?);
+/// assert_eq!(Decimal128::parse_str("1000E0")?.to_string(), "1000");
+/// assert_eq!(Decimal128::parse_str("1e3")?.to_string(), "1E+3");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Decimal128 {
     /// BSON bytes containing the decimal128. Stored for round tripping.
     pub(crate) bytes: [u8; 128 / 8],
@@ -23,6 +80,185 @@ impl Decimal128 {
         self.bytes
     }
 
+    /// Renders this value using the string representation described in the
+    /// [Decimal128 specification](https://github.com/mongodb/specifications/blob/master/source/bson-decimal128/decimal128.rst#to-string-representation),
+    /// which is also the format used for `$numberDecimal` in extended JSON.
+    pub(crate) fn to_spec_string(self) -> String {
+        let bits = u128::from_le_bytes(self.bytes);
+        let negative = bits >> 127 == 1;
+        let combination = (bits >> 122) & 0x1F;
+
+        if combination == 0x1F {
+            return "NaN".to_string();
+        }
+        if combination == 0x1E {
+            return if negative { "-Infinity" } else { "Infinity" }.to_string();
+        }
+
+        let (biased_exponent, coefficient) = if (bits >> 125) & 0b11 == 0b11 {
+            // The implied coefficient in this form always exceeds the maximum, so it is treated as
+            // zero.
+            ((bits >> 111) & 0x3FFF, 0)
+        } else {
+            ((bits >> 113) & 0x3FFF, bits & ((1 << 113) - 1))
+        };
+        let coefficient = if coefficient > MAX_COEFFICIENT {
+            0
+        } else {
+            coefficient
+        };
+        let exponent = biased_exponent as i32 - EXPONENT_BIAS;
+
+        let digits = coefficient.to_string();
+        let adjusted_exponent = exponent + digits.len() as i32 - 1;
+
+        let mut out = String::with_capacity(digits.len() + 8);
+        if negative {
+            out.push('-');
+        }
+
+        if exponent <= 0 && adjusted_exponent >= -6 {
+            if exponent == 0 {
+                out.push_str(&digits);
+            } else {
+                let point = digits.len() as i32 + exponent;
+                if point > 0 {
+                    let (int, frac) = digits.split_at(point as usize);
+                    out.push_str(int);
+                    out.push('.');
+                    out.push_str(frac);
+                } else {
+                    out.push_str("0.");
+                    out.push_str(&"0".repeat(-point as usize));
+                    out.push_str(&digits);
+                }
+            }
+        } else {
+            let (first, rest) = digits.split_at(1);
+            out.push_str(first);
+            if !rest.is_empty() {
+                out.push('.');
+                out.push_str(rest);
+            }
+            out.push('E');
+            if adjusted_exponent >= 0 {
+                out.push('+');
+            }
+            out.push_str(&adjusted_exponent.to_string());
+        }
+
+        out
+    }
+
+    /// Parses the string representation described in the
+    /// [Decimal128 specification](https://github.com/mongodb/specifications/blob/master/source/bson-decimal128/decimal128.rst#from-string-representation).
+    ///
+    /// Values that cannot be represented exactly (i.e. that would require rounding) are rejected.
+    pub fn parse_str(s: impl AsRef<str>) -> Result<Self> {
+        let s = s.as_ref();
+        let (negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        if unsigned.eq_ignore_ascii_case("nan") {
+            return Ok(Self::from_bits(0x1F << 122));
+        }
+        if unsigned.eq_ignore_ascii_case("inf") || unsigned.eq_ignore_ascii_case("infinity") {
+            return Ok(Self::from_bits((negative as u128) << 127 | 0x1E << 122));
+        }
+
+        let invalid = || Error::InvalidDecimalString {
+            input: s.to_string(),
+        };
+
+        let (significand, exponent) = match unsigned.find(&['e', 'E'][..]) {
+            Some(i) => (&unsigned[..i], Some(&unsigned[i + 1..])),
+            None => (unsigned, None),
+        };
+        let (int_part, frac_part) = match significand.find('.') {
+            Some(i) => (&significand[..i], &significand[i + 1..]),
+            None => (significand, ""),
+        };
+        if int_part.is_empty() && frac_part.is_empty()
+            || !int_part
+                .bytes()
+                .chain(frac_part.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let mut exponent: i64 = match exponent {
+            Some(e) => {
+                let digits = e.strip_prefix(&['+', '-'][..]).unwrap_or(e);
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                // Exponents this large are far outside the representable range either way, so
+                // saturating keeps the arithmetic below from overflowing.
+                let magnitude = digits.parse::<i64>().unwrap_or(i64::MAX).min(1 << 32);
+                if e.starts_with('-') {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+            None => 0,
+        };
+        exponent -= frac_part.len() as i64;
+
+        let mut digits: String = int_part.chars().chain(frac_part.chars()).collect();
+        let first_nonzero = digits.find(|c| c != '0');
+        let min_exponent = -(EXPONENT_BIAS as i64);
+        let max_exponent = MAX_BIASED_EXPONENT as i64 - EXPONENT_BIAS as i64;
+
+        match first_nonzero {
+            None => {
+                // Zero can be represented with any exponent, so out of range values are clamped.
+                digits = "0".to_string();
+                exponent = exponent.clamp(min_exponent, max_exponent);
+            }
+            Some(i) => {
+                digits.drain(..i);
+
+                while digits.len() > MAX_DIGITS && digits.ends_with('0') {
+                    digits.pop();
+                    exponent += 1;
+                }
+                while exponent > max_exponent && digits.len() < MAX_DIGITS {
+                    digits.push('0');
+                    exponent -= 1;
+                }
+                while exponent < min_exponent && digits.len() > 1 && digits.ends_with('0') {
+                    digits.pop();
+                    exponent += 1;
+                }
+
+                if digits.len() > MAX_DIGITS || exponent > max_exponent || exponent < min_exponent {
+                    return Err(Error::InexactValue {
+                        input: s.to_string(),
+                    });
+                }
+            }
+        }
+
+        // Unwrap safety: `digits` is at most 34 ASCII digits, which always fits in a u128.
+        let coefficient: u128 = digits.parse().unwrap();
+        let biased_exponent = (exponent + EXPONENT_BIAS as i64) as u128;
+
+        Ok(Self::from_bits(
+            (negative as u128) << 127 | biased_exponent << 113 | coefficient,
+        ))
+    }
+
+    fn from_bits(bits: u128) -> Self {
+        Self {
+            bytes: bits.to_le_bytes(),
+        }
+    }
+
     pub(crate) fn deserialize_from_slice<E: serde::de::Error>(
         bytes: &[u8],
     ) -> std::result::Result<Self, E> {
@@ -31,14 +267,24 @@ impl Decimal128 {
     }
 }
 
+impl FromStr for Decimal128 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse_str(s)
+    }
+}
+
 impl fmt::Debug for Decimal128 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Decimal128(...)")
+        f.debug_tuple("Decimal128")
+            .field(&self.to_spec_string())
+            .finish()
     }
 }
 
 impl fmt::Display for Decimal128 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(&self.to_spec_string())
     }
 }
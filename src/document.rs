@@ -1,11 +1,14 @@
 //! A BSON document represented as an associative HashMap with insertion ordering.
 
 use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet},
     error,
     fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
     io::{Read, Write},
     iter::{Extend, FromIterator, IntoIterator},
     mem,
+    ops::{Index, IndexMut},
 };
 
 use ahash::RandomState;
@@ -14,13 +17,21 @@ use serde::de::Error;
 
 use crate::{
     bson::{Array, Binary, Bson, Timestamp},
-    de::{deserialize_bson_kvp, ensure_read_exactly, read_i32, MIN_BSON_DOCUMENT_SIZE},
+    de::{self, deserialize_bson_kvp, ensure_read_exactly, read_i32, MIN_BSON_DOCUMENT_SIZE},
     oid::ObjectId,
+    raw::{RawBsonRef, RawDocument},
     ser::{serialize_bson, write_i32},
     spec::BinarySubtype,
     Decimal128,
 };
 
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub mod watch;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use self::watch::{into_watched, WatchedDocument};
+
 /// Error to indicate that either a value was empty or it contained an unexpected
 /// type, for use with the direct getters.
 #[derive(PartialEq, Clone)]
@@ -57,8 +68,69 @@ impl Display for ValueAccessError {
 
 impl error::Error for ValueAccessError {}
 
+/// Error to indicate that a dot-notation path (e.g. `"a.b.c"`) could not be applied to a
+/// [`Document`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum PathError {
+    /// The path was empty or contained an empty segment (e.g. `"a..b"`).
+    #[non_exhaustive]
+    EmptySegment {
+        /// The offending path.
+        path: String,
+    },
+
+    /// The path conflicts with an existing value, e.g. because a segment of it refers to a value
+    /// that is not a document.
+    #[non_exhaustive]
+    Conflict {
+        /// The prefix of the path at which the conflict occurred.
+        path: String,
+    },
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            PathError::EmptySegment { path } => write!(f, "path \"{}\" has an empty segment", path),
+            PathError::Conflict { path } => {
+                write!(f, "path \"{}\" conflicts with an existing value", path)
+            }
+        }
+    }
+}
+
+impl error::Error for PathError {}
+
+/// The maximum size, in bytes, of a BSON document accepted by a MongoDB server.
+///
+/// This is the limit checked by [`Document::is_within_size_limit`] and [`validate_document`].
+pub const MAX_BSON_SIZE: usize = 16 * 1024 * 1024;
+
+/// The maximum size, in bytes, of a document produced by [`Document::to_writer`] and
+/// [`crate::to_vec`], and of a document or array read by the decoding functions.
+///
+/// This matches the server's internal limit, which leaves 16 KiB of room for command overhead
+/// around a user document of [`MAX_BSON_SIZE`] bytes.
+pub const MAX_ENCODED_DOCUMENT_SIZE: usize = MAX_BSON_SIZE + 16 * 1024;
+
 /// A BSON document represented as an associative HashMap with insertion ordering.
-#[derive(Clone, PartialEq)]
+///
+/// Equality ignores insertion order: two documents are equal if they contain the same keys with
+/// equal values, which applies recursively to embedded documents, including those inside arrays.
+/// To also require the same key order, compare the documents' iterators, as in
+/// `a.iter().eq(b.iter())`.
+///
+/// ```
+/// use bson::{doc, Document};
+///
+/// let mut doc = Document::new();
+/// doc.insert("b", 1);
+/// doc.insert("a", "two");
+/// assert_eq!(doc, doc! { "b": 1, "a": "two" });
+/// assert_eq!(doc.keys().collect::<Vec<_>>(), ["b", "a"]);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
 pub struct Document {
     inner: IndexMap<String, Bson, RandomState>,
 }
@@ -69,6 +141,23 @@ impl Default for Document {
     }
 }
 
+/// Like its [`PartialEq`] implementation, a document's hash does not depend on the order of its
+/// keys.
+impl Hash for Document {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Combine the hashes of the individual entries with a commutative operation so that
+        // documents which compare equal hash identically regardless of insertion order.
+        let entries = self.inner.iter().fold(0u64, |acc, entry| {
+            let mut hasher = DefaultHasher::new();
+            entry.hash(&mut hasher);
+            acc.wrapping_add(hasher.finish())
+        });
+
+        state.write_usize(self.inner.len());
+        state.write_u64(entries);
+    }
+}
+
 impl Display for Document {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
         fmt.write_str("{")?;
@@ -92,27 +181,69 @@ impl Display for Document {
 impl Debug for Document {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
         write!(fmt, "Document(")?;
-        Debug::fmt(&self.inner, fmt)?;
+        fmt.debug_map().entries(self.iter()).finish()?;
         write!(fmt, ")")
     }
 }
 
-/// An iterator over Document entries.
+impl Index<&str> for Document {
+    type Output = Bson;
+
+    /// Returns a reference to the value corresponding to the supplied key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the document. Use [`Document::get`] for fallible
+    /// access.
+    fn index(&self, key: &str) -> &Bson {
+        match self.get(key) {
+            Some(value) => value,
+            None => panic!(
+                "key \"{}\" not present in document; use Document::get for fallible access",
+                key
+            ),
+        }
+    }
+}
+
+impl IndexMut<&str> for Document {
+    /// Returns a mutable reference to the value corresponding to the supplied key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the document. Use [`Document::get_mut`] for fallible
+    /// access.
+    fn index_mut(&mut self, key: &str) -> &mut Bson {
+        match self.get_mut(key) {
+            Some(value) => value,
+            None => panic!(
+                "key \"{}\" not present in document; use Document::get_mut for fallible access",
+                key
+            ),
+        }
+    }
+}
+
+/// An owning iterator over Document entries.
+#[derive(Debug)]
 pub struct IntoIter {
     inner: indexmap::map::IntoIter<String, Bson>,
 }
 
-/// An owning iterator over Document entries.
+/// An iterator over Document entries.
+#[derive(Clone, Debug)]
 pub struct Iter<'a> {
     inner: indexmap::map::Iter<'a, String, Bson>,
 }
 
 /// An iterator over an Document's keys.
+#[derive(Clone, Debug)]
 pub struct Keys<'a> {
     inner: indexmap::map::Keys<'a, String, Bson>,
 }
 
 /// An iterator over an Document's values.
+#[derive(Clone, Debug)]
 pub struct Values<'a> {
     inner: indexmap::map::Values<'a, String, Bson>,
 }
@@ -165,6 +296,14 @@ impl FromIterator<(String, Bson)> for Document {
     }
 }
 
+impl<'a> FromIterator<(&'a str, Bson)> for Document {
+    fn from_iter<T: IntoIterator<Item = (&'a str, Bson)>>(iter: T) -> Self {
+        let mut doc = Document::new();
+        doc.extend(iter);
+        doc
+    }
+}
+
 impl Iterator for IntoIter {
     type Item = (String, Bson);
 
@@ -189,8 +328,15 @@ impl Document {
         }
     }
 
+    /// Creates a new empty Document with space preallocated for at least `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Document {
+        Document {
+            inner: IndexMap::with_capacity_and_hasher(capacity, RandomState::default()),
+        }
+    }
+
     /// Gets an iterator over the entries of the map.
-    pub fn iter(&self) -> Iter {
+    pub fn iter(&self) -> Iter<'_> {
         self.into_iter()
     }
 
@@ -232,7 +378,7 @@ impl Document {
     /// Get a reference to a Decimal128 value for key, if it exists.
     pub fn get_decimal128(&self, key: impl AsRef<str>) -> ValueAccessResult<&Decimal128> {
         match self.get(key) {
-            Some(&Bson::Decimal128(ref v)) => Ok(v),
+            Some(Bson::Decimal128(v)) => Ok(v),
             Some(_) => Err(ValueAccessError::UnexpectedType),
             None => Err(ValueAccessError::NotPresent),
         }
@@ -253,7 +399,7 @@ impl Document {
     /// Get a string slice this key if it exists and has the correct type.
     pub fn get_str(&self, key: impl AsRef<str>) -> ValueAccessResult<&str> {
         match self.get(key) {
-            Some(&Bson::String(ref v)) => Ok(v),
+            Some(Bson::String(v)) => Ok(v),
             Some(_) => Err(ValueAccessError::UnexpectedType),
             None => Err(ValueAccessError::NotPresent),
         }
@@ -272,7 +418,7 @@ impl Document {
     /// the correct type.
     pub fn get_array(&self, key: impl AsRef<str>) -> ValueAccessResult<&Array> {
         match self.get(key) {
-            Some(&Bson::Array(ref v)) => Ok(v),
+            Some(Bson::Array(v)) => Ok(v),
             Some(_) => Err(ValueAccessError::UnexpectedType),
             None => Err(ValueAccessError::NotPresent),
         }
@@ -292,7 +438,7 @@ impl Document {
     /// the correct type.
     pub fn get_document(&self, key: impl AsRef<str>) -> ValueAccessResult<&Document> {
         match self.get(key) {
-            Some(&Bson::Document(ref v)) => Ok(v),
+            Some(Bson::Document(v)) => Ok(v),
             Some(_) => Err(ValueAccessError::UnexpectedType),
             None => Err(ValueAccessError::NotPresent),
         }
@@ -437,7 +583,7 @@ impl Document {
     /// Get a reference to a UTC datetime value for this key if it exists and has the correct type.
     pub fn get_datetime(&self, key: impl AsRef<str>) -> ValueAccessResult<&crate::DateTime> {
         match self.get(key) {
-            Some(&Bson::DateTime(ref v)) => Ok(v),
+            Some(Bson::DateTime(v)) => Ok(v),
             Some(_) => Err(ValueAccessError::UnexpectedType),
             None => Err(ValueAccessError::NotPresent),
         }
@@ -462,14 +608,14 @@ impl Document {
     }
 
     /// Gets a collection of all keys in the document.
-    pub fn keys(&self) -> Keys {
+    pub fn keys(&self) -> Keys<'_> {
         Keys {
             inner: self.inner.keys(),
         }
     }
 
     /// Gets a collection of all values in the document.
-    pub fn values(&self) -> Values {
+    pub fn values(&self) -> Values<'_> {
         Values {
             inner: self.inner.values(),
         }
@@ -488,6 +634,18 @@ impl Document {
     /// Sets the value of the entry with the OccupiedEntry's key,
     /// and returns the entry's old value. Accepts any type that
     /// can be converted into Bson.
+    ///
+    /// Replacing the value of an existing key keeps the key's original position.
+    ///
+    /// ```
+    /// use bson::{doc, Bson, Document};
+    ///
+    /// let mut doc = Document::new();
+    /// assert_eq!(doc.insert("x", 1), None);
+    /// doc.insert("y", 2);
+    /// assert_eq!(doc.insert("x", "one"), Some(Bson::Int32(1)));
+    /// assert_eq!(doc, doc! { "x": "one", "y": 2 });
+    /// ```
     pub fn insert<KT: Into<String>, BT: Into<Bson>>(&mut self, key: KT, val: BT) -> Option<Bson> {
         self.inner.insert(key.into(), val.into())
     }
@@ -498,13 +656,468 @@ impl Document {
         self.inner.shift_remove(key.as_ref())
     }
 
-    pub fn entry(&mut self, k: String) -> Entry {
+    /// Removes the first entry in the document and returns its key and value, or `None` if the
+    /// document is empty. Computes in **O(n)** time.
+    pub fn pop_first(&mut self) -> Option<(String, Bson)> {
+        self.inner.shift_remove_index(0)
+    }
+
+    /// Removes the last entry in the document and returns its key and value, or `None` if the
+    /// document is empty. Computes in **O(1)** time (average).
+    pub fn pop_last(&mut self) -> Option<(String, Bson)> {
+        self.inner.pop()
+    }
+
+    pub fn entry(&mut self, k: String) -> Entry<'_> {
         match self.inner.entry(k) {
             indexmap::map::Entry::Occupied(o) => Entry::Occupied(OccupiedEntry { inner: o }),
             indexmap::map::Entry::Vacant(v) => Entry::Vacant(VacantEntry { inner: v }),
         }
     }
 
+    /// Returns a mutable reference to the value for `key`, first inserting `default` if the key
+    /// isn't present. This is shorthand for `doc.entry(key).or_insert(default)`.
+    ///
+    /// ```
+    /// use bson::{doc, Bson};
+    ///
+    /// let mut doc = doc! { "count": 1 };
+    /// *doc.get_or_insert("count", 0) = Bson::Int32(2);
+    /// doc.get_or_insert("tags", Vec::<Bson>::new()).array_push("new");
+    /// assert_eq!(doc, doc! { "count": 2, "tags": ["new"] });
+    /// ```
+    pub fn get_or_insert(&mut self, key: impl Into<String>, default: impl Into<Bson>) -> &mut Bson {
+        self.entry(key.into()).or_insert(default.into())
+    }
+
+    /// Renames the key `old_key` to `new_key`, keeping the entry at the same position in the
+    /// document. Computes in **O(n)** time.
+    ///
+    /// Returns `false` and leaves the document unchanged if `old_key` is not present, or if a
+    /// different entry already uses `new_key`; to overwrite that entry instead, [`remove`] it
+    /// first.
+    ///
+    /// [`remove`]: Document::remove
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let mut doc = doc! { "a": 1, "b": 2, "c": 3 };
+    /// assert!(doc.rename_key("b", "renamed"));
+    /// assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "renamed", "c"]);
+    ///
+    /// assert!(!doc.rename_key("missing", "x"));
+    /// assert!(!doc.rename_key("a", "c"));
+    /// ```
+    pub fn rename_key(&mut self, old_key: &str, new_key: impl Into<String>) -> bool {
+        let mut new_key = new_key.into();
+        if !self.contains_key(old_key) || (new_key != old_key && self.contains_key(&new_key)) {
+            return false;
+        }
+
+        self.inner = mem::take(&mut self.inner)
+            .into_iter()
+            .map(|(k, v)| {
+                if k == old_key {
+                    (mem::take(&mut new_key), v)
+                } else {
+                    (k, v)
+                }
+            })
+            .collect();
+        true
+    }
+
+    /// Sorts the entries of this document lexicographically by key, in place.
+    ///
+    /// This produces the canonical key order required by some MongoDB commands and is useful for
+    /// deterministic content hashing. Note that only the top-level entries are sorted; values that
+    /// are themselves documents are left untouched unless the caller explicitly recurses into them.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let mut doc = doc! { "b": 1, "a": { "z": 1, "y": 2 } };
+    /// doc.sort_keys();
+    /// assert_eq!(doc.keys().collect::<Vec<_>>(), vec!["a", "b"]);
+    /// assert!(!doc.get_document("a").unwrap().is_sorted());
+    /// ```
+    pub fn sort_keys(&mut self) {
+        self.inner.sort_keys();
+    }
+
+    /// Consumes this document and returns it with its entries sorted lexicographically by key.
+    /// See [`Document::sort_keys`] for details.
+    pub fn into_sorted(mut self) -> Document {
+        self.sort_keys();
+        self
+    }
+
+    /// Returns whether the top-level entries of this document are sorted lexicographically by
+    /// key.
+    pub fn is_sorted(&self) -> bool {
+        let mut keys = self.inner.keys();
+        let mut previous = match keys.next() {
+            Some(key) => key,
+            None => return true,
+        };
+
+        for key in keys {
+            if previous > key {
+                return false;
+            }
+            previous = key;
+        }
+        true
+    }
+
+    /// Recursively merges `other` into this document.
+    ///
+    /// When both documents contain a key whose values are both documents, those subdocuments are
+    /// merged recursively. Otherwise, the value from `other` replaces the one in this document.
+    /// Keys already present in this document keep their position; new keys are appended.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let mut doc = doc! { "a": { "x": 1, "y": 2 }, "b": 1 };
+    /// doc.deep_merge(doc! { "a": { "y": 3, "z": 4 }, "b": { "c": true } });
+    /// assert_eq!(doc, doc! { "a": { "x": 1, "y": 3, "z": 4 }, "b": { "c": true } });
+    /// ```
+    pub fn deep_merge(&mut self, other: Document) {
+        for (key, value) in other {
+            match (self.inner.get_mut(&key), value) {
+                (Some(Bson::Document(existing)), Bson::Document(incoming)) => {
+                    existing.deep_merge(incoming)
+                }
+                (_, value) => {
+                    self.inner.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Returns a copy of this document with all nested subdocuments flattened into top-level keys
+    /// using dot notation, as expected by MongoDB update operators such as `$set`.
+    ///
+    /// Arrays are not flattened, and empty subdocuments are retained as-is so that no information
+    /// is lost. See [`Document::unflatten`] for the inverse.
+    ///
+    /// An error is returned if a key that already contains a dot collides with a flattened path
+    /// (e.g. `{ "a": { "b": 1 }, "a.b": 2 }`).
+    ///
+    /// ```
+    /// # fn main() -> Result<(), bson::document::PathError> {
+    /// use bson::doc;
+    ///
+    /// let doc = doc! { "a": { "b": 1, "c": { "d": [1, 2] } }, "e": {} };
+    /// assert_eq!(doc.flatten()?, doc! { "a.b": 1, "a.c.d": [1, 2], "e": {} });
+    ///
+    /// assert!(doc! { "a": { "b": 1 }, "a.b": 2 }.flatten().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flatten(&self) -> Result<Document, PathError> {
+        fn flatten_into(prefix: &str, doc: &Document, out: &mut Document) -> Result<(), PathError> {
+            for (key, value) in doc {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+
+                match value {
+                    Bson::Document(subdoc) if !subdoc.is_empty() => {
+                        flatten_into(&path, subdoc, out)?
+                    }
+                    _ if out.contains_key(&path) => return Err(PathError::Conflict { path }),
+                    other => {
+                        out.insert(path, other.clone());
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        let mut out = Document::new();
+        flatten_into("", self, &mut out)?;
+        Ok(out)
+    }
+
+    /// Reconstructs a nested document from one containing dot-notation keys, undoing
+    /// [`Document::flatten`].
+    ///
+    /// An error is returned if a key contains an empty segment or if two keys conflict with each
+    /// other (e.g. `"a"` and `"a.b"` where `"a"` is not a document).
+    ///
+    /// ```
+    /// # fn main() -> Result<(), bson::document::PathError> {
+    /// use bson::{doc, Document};
+    ///
+    /// let flat = doc! { "a.b": 1, "a.c": 2, "d": true };
+    /// assert_eq!(Document::unflatten(&flat)?, doc! { "a": { "b": 1, "c": 2 }, "d": true });
+    ///
+    /// assert!(Document::unflatten(&doc! { "a": 1, "a.b": 2 }).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unflatten(flat: &Document) -> Result<Document, PathError> {
+        let mut out = Document::new();
+
+        for (key, value) in flat {
+            let (parent, last) = out.walk_path_mut(key)?;
+            if parent.contains_key(last) {
+                return Err(PathError::Conflict { path: key.clone() });
+            }
+            parent.insert(last, value.clone());
+        }
+
+        Ok(out)
+    }
+
+    /// Sets the value at the given dot-notation path (e.g. `"a.b.c"`), creating any missing
+    /// intermediate documents, like MongoDB's `$set` update operator.
+    ///
+    /// An error is returned if the path contains an empty segment or if one of its intermediate
+    /// segments refers to a value that is not a document.
+    ///
+    /// ```
+    /// # fn main() -> Result<(), bson::document::PathError> {
+    /// use bson::doc;
+    ///
+    /// let mut doc = doc! { "a": { "b": 1 } };
+    /// doc.set("a.c.d", "new")?;
+    /// assert_eq!(doc, doc! { "a": { "b": 1, "c": { "d": "new" } } });
+    ///
+    /// assert!(doc.set("a.b.x", 2).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set(&mut self, path: &str, value: impl Into<Bson>) -> Result<(), PathError> {
+        let (parent, last) = self.walk_path_mut(path)?;
+        parent.insert(last, value);
+        Ok(())
+    }
+
+    /// Removes the value at the given dot-notation path, like MongoDB's `$unset` update
+    /// operator. Returns whether a value was removed.
+    ///
+    /// Intermediate documents are left in place even if they become empty.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let mut doc = doc! { "a": { "b": 1, "c": 2 } };
+    /// assert!(doc.unset("a.b"));
+    /// assert!(!doc.unset("a.x.y"));
+    /// assert_eq!(doc, doc! { "a": { "c": 2 } });
+    /// ```
+    pub fn unset(&mut self, path: &str) -> bool {
+        let (parents, last) = match path.rsplit_once('.') {
+            Some((parents, last)) => (Some(parents), last),
+            None => (None, path),
+        };
+
+        let mut current = self;
+        for segment in parents.into_iter().flat_map(|parents| parents.split('.')) {
+            current = match current.get_mut(segment) {
+                Some(Bson::Document(doc)) => doc,
+                _ => return false,
+            };
+        }
+        current.remove(last).is_some()
+    }
+
+    /// Appends a value to the array at the given dot-notation path, like MongoDB's `$push` update
+    /// operator. If nothing is present at the path, a new single-element array is created along
+    /// with any missing intermediate documents.
+    ///
+    /// An error is returned if the path contains an empty segment, if one of its intermediate
+    /// segments refers to a value that is not a document, or if the value at the path is not an
+    /// array.
+    ///
+    /// ```
+    /// # fn main() -> Result<(), bson::document::PathError> {
+    /// use bson::doc;
+    ///
+    /// let mut doc = doc! { "a": { "tags": ["x"] } };
+    /// doc.array_push("a.tags", "y")?;
+    /// doc.array_push("a.scores", 10)?;
+    /// assert_eq!(doc, doc! { "a": { "tags": ["x", "y"], "scores": [10] } });
+    ///
+    /// assert!(doc.array_push("a", 1).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn array_push(&mut self, path: &str, value: impl Into<Bson>) -> Result<(), PathError> {
+        let (parent, last) = self.walk_path_mut(path)?;
+        match parent
+            .inner
+            .entry(last.to_string())
+            .or_insert_with(|| Bson::Array(Vec::new()))
+        {
+            Bson::Array(array) => {
+                array.push(value.into());
+                Ok(())
+            }
+            _ => Err(PathError::Conflict { path: path.into() }),
+        }
+    }
+
+    /// Returns a copy of this document containing only the given fields, like an inclusion
+    /// projection in MongoDB. Fields may be top-level keys or dot-notation paths into nested
+    /// documents (e.g. `"address.city"`); paths are not applied to the elements of arrays.
+    ///
+    /// Fields keep their order from this document, and fields that are not present are ignored.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let doc = doc! { "name": "Alice", "address": { "city": "Paris", "zip": "75001" }, "age": 30 };
+    /// assert_eq!(
+    ///     doc.project(&["age", "address.city"]),
+    ///     doc! { "address": { "city": "Paris" }, "age": 30 },
+    /// );
+    /// ```
+    pub fn project(&self, fields: &[&str]) -> Document {
+        let mut out = Document::new();
+        for (key, value) in self {
+            let mut whole = false;
+            let mut nested = Vec::new();
+            for field in fields {
+                if field == key {
+                    whole = true;
+                    break;
+                } else if let Some(rest) = field
+                    .strip_prefix(key.as_str())
+                    .and_then(|rest| rest.strip_prefix('.'))
+                {
+                    nested.push(rest);
+                }
+            }
+
+            if whole {
+                out.insert(key.clone(), value.clone());
+            } else if let Bson::Document(doc) = value {
+                if !nested.is_empty() {
+                    out.insert(key.clone(), doc.project(&nested));
+                }
+            }
+        }
+        out
+    }
+
+    /// Returns a copy of this document with the given fields removed, like an exclusion
+    /// projection in MongoDB. Fields may be top-level keys or dot-notation paths into nested
+    /// documents, and fields that are not present are ignored. See [`Document::unset`].
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let doc = doc! { "name": "Alice", "auth": { "password": "hunter2", "role": "admin" } };
+    /// assert_eq!(
+    ///     doc.project_exclude(&["auth.password"]),
+    ///     doc! { "name": "Alice", "auth": { "role": "admin" } },
+    /// );
+    /// ```
+    pub fn project_exclude(&self, fields: &[&str]) -> Document {
+        let mut out = self.clone();
+        for field in fields {
+            out.unset(field);
+        }
+        out
+    }
+
+    /// Walks all but the last segment of the given dot-notation path, creating any missing
+    /// intermediate documents, and returns the innermost document along with the final segment.
+    fn walk_path_mut<'a, 'p>(
+        &'a mut self,
+        path: &'p str,
+    ) -> Result<(&'a mut Document, &'p str), PathError> {
+        let mut segments = path.split('.').peekable();
+        let mut current = self;
+        let mut consumed = 0;
+
+        while let Some(segment) = segments.next() {
+            if segment.is_empty() {
+                return Err(PathError::EmptySegment { path: path.into() });
+            }
+
+            if segments.peek().is_none() {
+                return Ok((current, segment));
+            }
+
+            consumed += segment.len();
+            current = match current
+                .inner
+                .entry(segment.to_string())
+                .or_insert_with(|| Bson::Document(Document::new()))
+            {
+                Bson::Document(doc) => doc,
+                _ => {
+                    return Err(PathError::Conflict {
+                        path: path[..consumed].into(),
+                    })
+                }
+            };
+            consumed += 1;
+        }
+
+        unreachable!("str::split always yields at least one segment")
+    }
+
+    /// Parses a document from its [extended JSON v2 representation](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/).
+    /// See [`Bson::from_relaxed_extjson`] for details.
+    ///
+    /// Returns an error if the value is not a JSON object, or if it is an object that represents a
+    /// BSON value other than a document (e.g. `{"$oid": "..."}`).
+    pub fn from_relaxed_extjson(value: serde_json::Value) -> crate::extjson::de::Result<Document> {
+        match Bson::from_relaxed_extjson(value)? {
+            Bson::Document(doc) => Ok(doc),
+            other => Err(crate::extjson::de::Error::DeserializationError {
+                message: format!("expected a document, got {:?}", other.element_type()),
+            }),
+        }
+    }
+
+    /// Returns the [canonical extended JSON representation](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/)
+    /// of this document. See [`Bson::to_canonical_extjson`] for details.
+    pub fn to_canonical_extjson(&self) -> serde_json::Value {
+        Bson::Document(self.clone()).into_canonical_extjson()
+    }
+
+    /// Renders this document as a [relaxed extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/) string.
+    /// See [`Bson::to_json_string`] for details.
+    pub fn to_json_string(&self) -> String {
+        Bson::Document(self.clone()).to_json_string()
+    }
+
+    /// Returns the number of bytes this document occupies when encoded as BSON, computed without
+    /// encoding it.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let doc = doc! { "x": 1, "s": "hi" };
+    /// let mut bytes = Vec::new();
+    /// doc.to_writer(&mut bytes)?;
+    /// assert_eq!(doc.byte_len(), bytes.len());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn byte_len(&self) -> usize {
+        elements_byte_len(self.iter().map(|(k, v)| (k.len(), v)))
+    }
+
+    /// Returns whether this document's encoded size is no larger than [`MAX_BSON_SIZE`], the
+    /// largest document a MongoDB server will accept.
+    ///
+    /// [`Document::to_writer`] allows documents up to the slightly larger
+    /// [`MAX_ENCODED_DOCUMENT_SIZE`], so a document for which this returns `false` may still be
+    /// encoded, e.g. to be embedded in a command.
+    pub fn is_within_size_limit(&self) -> bool {
+        self.byte_len() <= MAX_BSON_SIZE
+    }
+
     /// Attempts to serialize the `Document` into a byte stream.
     ///
     /// While the method signature indicates an owned writer must be passed in, a mutable reference
@@ -521,7 +1134,30 @@ impl Document {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn to_writer<W: Write>(&self, mut writer: W) -> crate::ser::Result<()> {
+    ///
+    /// Documents whose encoded size exceeds [`MAX_ENCODED_DOCUMENT_SIZE`] are rejected with
+    /// [`ser::Error::DocumentTooLarge`](crate::ser::Error::DocumentTooLarge) before anything is
+    /// written.
+    ///
+    /// [`crate::encode_document`] and [`crate::encode_document_to_vec`] provide the same
+    /// functionality as free functions.
+    pub fn to_writer<W: Write>(&self, writer: W) -> crate::ser::Result<()> {
+        let actual = self.byte_len();
+        if actual > MAX_ENCODED_DOCUMENT_SIZE {
+            return Err(crate::ser::Error::DocumentTooLarge {
+                actual,
+                limit: MAX_ENCODED_DOCUMENT_SIZE,
+            });
+        }
+        self.write_without_size_check(writer)
+    }
+
+    /// Serializes the document without checking its total size. Used for embedded documents,
+    /// which are covered by the check performed for the top-level document.
+    pub(crate) fn write_without_size_check<W: Write>(
+        &self,
+        mut writer: W,
+    ) -> crate::ser::Result<()> {
         let mut buf = Vec::new();
         for (key, val) in self.into_iter() {
             serialize_bson(&mut buf, key.as_ref(), val)?;
@@ -540,10 +1176,15 @@ impl Document {
         let mut doc = Document::new();
 
         let length = read_i32(reader)?;
-        if length < MIN_BSON_DOCUMENT_SIZE {
+        if !(MIN_BSON_DOCUMENT_SIZE..=de::MAX_ENCODED_DOCUMENT_SIZE).contains(&length) {
             return Err(crate::de::Error::invalid_length(
                 length as usize,
-                &"document length must be at least 5",
+                &format!(
+                    "document length must be between {} and {}",
+                    MIN_BSON_DOCUMENT_SIZE,
+                    de::MAX_ENCODED_DOCUMENT_SIZE
+                )
+                .as_str(),
             ));
         }
 
@@ -599,6 +1240,10 @@ impl Document {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// [`crate::decode_document`] and [`crate::decode_document_from_slice`] provide the same
+    /// functionality as free functions, and [`crate::DocumentReader`] reads a sequence of
+    /// documents from a single stream.
     pub fn from_reader<R: Read>(mut reader: R) -> crate::de::Result<Document> {
         Self::decode(&mut reader, false)
     }
@@ -614,6 +1259,344 @@ impl Document {
     }
 }
 
+/// The encoded length of a document (or array) whose elements have keys of the given lengths:
+/// a 4 byte length prefix, each element's type byte, key, and value, and a trailing null byte.
+fn elements_byte_len<'a>(elements: impl Iterator<Item = (usize, &'a Bson)>) -> usize {
+    let elements: usize = elements
+        .map(|(key_len, value)| 1 + key_len + 1 + value_byte_len(value))
+        .sum();
+    4 + elements + 1
+}
+
+/// The encoded length of a BSON value, excluding its type byte and key.
+fn value_byte_len(value: &Bson) -> usize {
+    // length prefix, contents, and null terminator.
+    fn string_len(s: &str) -> usize {
+        4 + s.len() + 1
+    }
+
+    match value {
+        Bson::Double(_) | Bson::Int64(_) | Bson::DateTime(_) | Bson::Timestamp(_) => 8,
+        Bson::Int32(_) => 4,
+        Bson::Boolean(_) => 1,
+        Bson::Null | Bson::Undefined | Bson::MaxKey | Bson::MinKey => 0,
+        Bson::ObjectId(_) => 12,
+        Bson::Decimal128(_) => 16,
+        Bson::String(s) | Bson::JavaScriptCode(s) | Bson::Symbol(s) => string_len(s),
+        Bson::Document(doc) => doc.byte_len(),
+        Bson::Array(arr) => {
+            elements_byte_len(arr.iter().enumerate().map(|(i, v)| (decimal_digits(i), v)))
+        }
+        Bson::RegularExpression(regex) => regex.pattern.len() + 1 + regex.options.len() + 1,
+        Bson::JavaScriptCodeWithScope(code_w_scope) => {
+            4 + string_len(&code_w_scope.code) + code_w_scope.scope.byte_len()
+        }
+        Bson::Binary(binary) => {
+            let old_length_prefix = if let BinarySubtype::BinaryOld = binary.subtype {
+                4
+            } else {
+                0
+            };
+            4 + 1 + old_length_prefix + binary.bytes.len()
+        }
+        Bson::DbPointer(db_pointer) => string_len(&db_pointer.namespace) + 12,
+    }
+}
+
+/// The number of characters in the decimal representation of `n`, i.e. the length of the key
+/// used for the array element at index `n`.
+fn decimal_digits(mut n: usize) -> usize {
+    let mut digits = 1;
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// A problem found in a document by [`validate_document`] or [`validate_raw_document`].
+///
+/// Keys are reported as dot-notation paths from the root of the validated document, with array
+/// elements identified by their index (e.g. `"users.2.$email"`).
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// A key appears more than once in the same document. This can only be reported by
+    /// [`validate_raw_document`], since a [`Document`] cannot contain duplicate keys.
+    DuplicateKey(String),
+
+    /// A key contains a `.`, which conflicts with MongoDB's dot notation.
+    KeyContainsDot(String),
+
+    /// A key starts with a `$`, which MongoDB reserves for operators.
+    KeyStartsWithDollar(String),
+
+    /// A key contains a null byte, which cannot be represented in BSON.
+    EmbeddedNullInKey(String),
+
+    /// The encoded document is larger than [`MAX_BSON_SIZE`].
+    #[non_exhaustive]
+    DocumentTooLarge {
+        /// The encoded size of the document, in bytes.
+        actual: usize,
+    },
+
+    /// A key or string value is not valid UTF-8. This can only be reported by
+    /// [`validate_raw_document`], since Rust strings are always valid UTF-8.
+    InvalidUtf8InString(String),
+
+    /// The raw document is not valid BSON, so validation could not continue past this point.
+    Malformed(crate::raw::Error),
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ValidationError::DuplicateKey(key) => write!(f, "duplicate key \"{}\"", key),
+            ValidationError::KeyContainsDot(key) => write!(f, "key \"{}\" contains a '.'", key),
+            ValidationError::KeyStartsWithDollar(key) => {
+                write!(f, "key \"{}\" starts with a '$'", key)
+            }
+            ValidationError::EmbeddedNullInKey(key) => {
+                write!(f, "key {:?} contains a null byte", key)
+            }
+            ValidationError::DocumentTooLarge { actual } => write!(
+                f,
+                "document of {} bytes exceeds the maximum size of {} bytes",
+                actual, MAX_BSON_SIZE
+            ),
+            ValidationError::InvalidUtf8InString(key) => {
+                write!(f, "invalid UTF-8 at key \"{}\"", key)
+            }
+            ValidationError::Malformed(e) => write!(f, "malformed BSON: {}", e),
+        }
+    }
+}
+
+impl error::Error for ValidationError {}
+
+/// Checks that a document can be stored by a MongoDB server, collecting every problem found rather
+/// than stopping at the first one.
+///
+/// Keys are checked for `.` and `$` characters and null bytes, recursing into embedded documents
+/// and arrays, and the encoded size of the document is checked against [`MAX_BSON_SIZE`]. Note
+/// that this rejects `$`-prefixed keys even though newer servers permit them in some contexts, and
+/// that documents meant to be update or query operators (e.g. `{ "$set": ... }`) should not be
+/// validated with this function.
+///
+/// ```
+/// use bson::{doc, document::{validate_document, ValidationError}};
+///
+/// assert_eq!(validate_document(&doc! { "a": { "b": [1, 2] } }), Ok(()));
+/// assert_eq!(
+///     validate_document(&doc! { "a.b": 1, "c": [{ "$d": 1 }] }),
+///     Err(vec![
+///         ValidationError::KeyContainsDot("a.b".to_string()),
+///         ValidationError::KeyStartsWithDollar("c.0.$d".to_string()),
+///     ])
+/// );
+/// ```
+pub fn validate_document(doc: &Document) -> Result<(), Vec<ValidationError>> {
+    fn validate_value(path: &str, value: &Bson, errors: &mut Vec<ValidationError>) {
+        match value {
+            Bson::Document(doc) => validate_keys(path, doc, errors),
+            Bson::Array(array) => {
+                for (i, value) in array.iter().enumerate() {
+                    validate_value(&join_path(path, &i.to_string()), value, errors);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_keys(prefix: &str, doc: &Document, errors: &mut Vec<ValidationError>) {
+        for (key, value) in doc {
+            let path = join_path(prefix, key);
+            validate_key(key, &path, errors);
+            validate_value(&path, value, errors);
+        }
+    }
+
+    let mut errors = Vec::new();
+    validate_keys("", doc, &mut errors);
+
+    let actual = doc.byte_len();
+    if actual > MAX_BSON_SIZE {
+        errors.push(ValidationError::DocumentTooLarge { actual });
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks that a raw document can be stored by a MongoDB server, collecting every problem found
+/// rather than stopping at the first one.
+///
+/// This performs the same checks as [`validate_document`], and additionally detects duplicate keys
+/// and invalid UTF-8, which cannot occur in a [`Document`]. String values that are not valid UTF-8
+/// are skipped using their length prefix, so the elements after them are still checked. If the
+/// bytes are malformed, or a key or regular expression is not valid UTF-8, the error is reported
+/// and the rest of the enclosing document is skipped.
+pub fn validate_raw_document(doc: &RawDocument) -> Result<(), Vec<ValidationError>> {
+    fn validate_elements(
+        prefix: &str,
+        doc: &RawDocument,
+        is_array: bool,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let mut seen = HashSet::new();
+        let mut iter = doc.into_iter();
+        while let Some(element) = iter.next() {
+            // a value of `None` means that the value is a string that is not valid UTF-8.
+            let (key, value) = match element {
+                Ok((key, value)) => (key, Some(value)),
+                Err(e) => match e.kind {
+                    crate::raw::ErrorKind::Utf8EncodingError(_) => {
+                        match iter.skip_invalid_utf8_value() {
+                            Some(key) => (key, None),
+                            None => {
+                                errors.push(ValidationError::InvalidUtf8InString(join_path(
+                                    prefix,
+                                    e.key().unwrap_or_default(),
+                                )));
+                                return;
+                            }
+                        }
+                    }
+                    _ => {
+                        errors.push(ValidationError::Malformed(e));
+                        return;
+                    }
+                },
+            };
+
+            let path = join_path(prefix, key);
+            if !is_array {
+                validate_key(key, &path, errors);
+                if !seen.insert(key) {
+                    errors.push(ValidationError::DuplicateKey(path.clone()));
+                }
+            }
+
+            match value {
+                Some(RawBsonRef::Document(doc)) => validate_elements(&path, doc, false, errors),
+                Some(RawBsonRef::Array(array)) => {
+                    validate_elements(&path, &array.doc, true, errors)
+                }
+                Some(_) => {}
+                None => errors.push(ValidationError::InvalidUtf8InString(path)),
+            }
+        }
+    }
+
+    let mut errors = Vec::new();
+    validate_elements("", doc, false, &mut errors);
+
+    let actual = doc.as_bytes().len();
+    if actual > MAX_BSON_SIZE {
+        errors.push(ValidationError::DocumentTooLarge { actual });
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// The differences between two documents, as computed by [`diff`].
+///
+/// Changes within embedded documents are reported at the most specific key that differs, using
+/// dot notation (e.g. `"address.city"`), so the keys of `added` and `modified` can be used directly
+/// in a `$set` update and the entries of `removed` in an `$unset` update. Arrays and other values
+/// are compared as a whole.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DocumentDiff {
+    /// Fields that are present in the new document but not in the old one, with their new values.
+    pub added: Document,
+
+    /// Keys of fields that are present in the old document but not in the new one.
+    pub removed: Vec<String>,
+
+    /// Fields that are present in both documents with different values, with their new values.
+    pub modified: Document,
+}
+
+impl DocumentDiff {
+    /// Returns whether the two documents were equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// Computes the fields that were added, removed or modified to get from `old` to `new`, recursing
+/// into embedded documents present in both.
+///
+/// ```
+/// use bson::{doc, document::diff};
+///
+/// let old = doc! { "name": "ferris", "address": { "city": "Paris", "zip": "75001" }, "age": 5 };
+/// let new = doc! { "name": "ferris", "address": { "city": "Lyon", "zip": "75001" }, "pets": 1 };
+///
+/// let diff = diff(&old, &new);
+/// assert_eq!(diff.added, doc! { "pets": 1 });
+/// assert_eq!(diff.removed, vec!["age".to_string()]);
+/// assert_eq!(diff.modified, doc! { "address.city": "Lyon" });
+/// ```
+pub fn diff(old: &Document, new: &Document) -> DocumentDiff {
+    fn diff_into(prefix: &str, old: &Document, new: &Document, diff: &mut DocumentDiff) {
+        for (key, new_value) in new {
+            let path = join_path(prefix, key);
+            match (old.get(key), new_value) {
+                (None, _) => {
+                    diff.added.insert(path, new_value.clone());
+                }
+                (Some(Bson::Document(old_doc)), Bson::Document(new_doc)) => {
+                    diff_into(&path, old_doc, new_doc, diff)
+                }
+                (Some(old_value), _) if old_value != new_value => {
+                    diff.modified.insert(path, new_value.clone());
+                }
+                _ => {}
+            }
+        }
+
+        for key in old.keys() {
+            if !new.contains_key(key) {
+                diff.removed.push(join_path(prefix, key));
+            }
+        }
+    }
+
+    let mut result = DocumentDiff::default();
+    diff_into("", old, new, &mut result);
+    result
+}
+
+fn validate_key(key: &str, path: &str, errors: &mut Vec<ValidationError>) {
+    if key.contains('\0') {
+        errors.push(ValidationError::EmbeddedNullInKey(path.to_string()));
+    }
+    if key.starts_with('$') {
+        errors.push(ValidationError::KeyStartsWithDollar(path.to_string()));
+    }
+    if key.contains('.') {
+        errors.push(ValidationError::KeyContainsDot(path.to_string()));
+    }
+}
+
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
 /// A view into a single entry in a map, which may either be vacant or occupied.
 ///
 /// This enum is constructed from the entry method on HashMap.
@@ -687,3 +1670,35 @@ impl Extend<(String, Bson)> for Document {
         }
     }
 }
+
+impl<'a> Extend<(&'a str, Bson)> for Document {
+    fn extend<T: IntoIterator<Item = (&'a str, Bson)>>(&mut self, iter: T) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+/// The entries of the resulting document are in the `HashMap`'s (arbitrary) iteration order.
+impl<S> From<HashMap<String, Bson, S>> for Document {
+    fn from(map: HashMap<String, Bson, S>) -> Self {
+        let mut doc = Document::with_capacity(map.len());
+        doc.extend(map);
+        doc
+    }
+}
+
+/// The entries of the resulting document are sorted by key, matching the `BTreeMap`'s order.
+impl From<BTreeMap<String, Bson>> for Document {
+    fn from(map: BTreeMap<String, Bson>) -> Self {
+        let mut doc = Document::with_capacity(map.len());
+        doc.extend(map);
+        doc
+    }
+}
+
+impl From<Document> for HashMap<String, Bson> {
+    fn from(doc: Document) -> Self {
+        doc.into_iter().collect()
+    }
+}
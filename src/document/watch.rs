@@ -0,0 +1,102 @@
+//! Observation of [`Document`] mutations via a [`tokio::sync::watch`] channel.
+
+use std::ops::Deref;
+
+use tokio::sync::watch;
+
+use crate::{Bson, Document};
+
+/// Wraps the given [`Document`] in a [`WatchedDocument`], returning it alongside a
+/// [`watch::Receiver`] that observes every subsequent version of the document.
+///
+/// The receiver initially holds the provided document and can be awaited via
+/// [`watch::Receiver::changed`] to be notified of the next mutation.
+///
+/// ```
+/// use bson::{doc, document::into_watched};
+///
+/// let (mut watched, mut rx) = into_watched(doc! { "x": 1 });
+///
+/// watched.insert("y", 2);
+/// assert!(rx.has_changed().unwrap());
+/// assert_eq!(*rx.borrow_and_update(), doc! { "x": 1, "y": 2 });
+/// ```
+pub fn into_watched(doc: Document) -> (WatchedDocument, watch::Receiver<Document>) {
+    let (sender, receiver) = watch::channel(doc.clone());
+    (WatchedDocument { inner: doc, sender }, receiver)
+}
+
+/// A [`Document`] that publishes a clone of its current state to a [`watch`] channel every time
+/// it is mutated.
+///
+/// Read access is provided through [`Deref`]; mutations must go through the methods on this type
+/// so that they can be observed. Publishing never fails, even if every receiver has been dropped.
+#[derive(Debug)]
+pub struct WatchedDocument {
+    inner: Document,
+    sender: watch::Sender<Document>,
+}
+
+impl WatchedDocument {
+    /// Creates a new [`watch::Receiver`] that observes this document, starting from its current
+    /// state.
+    pub fn subscribe(&self) -> watch::Receiver<Document> {
+        self.sender.subscribe()
+    }
+
+    /// Sets the value of the entry with the given key and publishes the updated document,
+    /// returning the entry's old value.
+    pub fn insert<KT: Into<String>, BT: Into<Bson>>(&mut self, key: KT, val: BT) -> Option<Bson> {
+        let old = self.inner.insert(key, val);
+        self.publish();
+        old
+    }
+
+    /// Takes the value of the entry out of the document and returns it. The updated document is
+    /// only published if an entry was actually removed.
+    pub fn remove(&mut self, key: impl AsRef<str>) -> Option<Bson> {
+        let removed = self.inner.remove(key);
+        if removed.is_some() {
+            self.publish();
+        }
+        removed
+    }
+
+    /// Clears the document, removing all values, and publishes the now empty document.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.publish();
+    }
+
+    /// Replaces the entire document with the provided one and publishes it, returning the
+    /// previous document.
+    pub fn set(&mut self, doc: Document) -> Document {
+        let old = std::mem::replace(&mut self.inner, doc);
+        self.publish();
+        old
+    }
+
+    /// Consumes the wrapper, returning the underlying document. Receivers will observe that the
+    /// sender has been dropped.
+    pub fn into_inner(self) -> Document {
+        self.inner
+    }
+
+    fn publish(&self) {
+        self.sender.send_replace(self.inner.clone());
+    }
+}
+
+impl Deref for WatchedDocument {
+    type Target = Document;
+
+    fn deref(&self) -> &Document {
+        &self.inner
+    }
+}
+
+impl AsRef<Document> for WatchedDocument {
+    fn as_ref(&self) -> &Document {
+        &self.inner
+    }
+}
@@ -160,7 +160,8 @@ impl TryFrom<serde_json::Map<String, serde_json::Value>> for Bson {
         }
 
         if obj.contains_key("$numberDecimal") {
-            return Err(Error::custom("decimal128 extjson support not implemented"));
+            let decimal: models::Decimal128 = serde_json::from_value(obj.into())?;
+            return Ok(Bson::Decimal128(decimal.parse()?));
         }
 
         if obj.contains_key("$undefined") {
@@ -181,7 +182,7 @@ impl TryFrom<serde_json::Value> for Bson {
             serde_json::Value::Number(x) => x
                 .as_i64()
                 .map(|i| {
-                    if i >= std::i32::MIN as i64 && i <= std::i32::MAX as i64 {
+                    if i >= i32::MIN as i64 && i <= i32::MAX as i64 {
                         Bson::Int32(i as i32)
                     } else {
                         Bson::Int64(i)
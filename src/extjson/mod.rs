@@ -26,8 +26,7 @@
 //! There are two modes of extJSON: "Canonical" and "Relaxed". They are the same except for the
 //! following differences:
 //!   - In relaxed mode, all BSON numbers are represented by the JSON number type, rather than the
-//!     object
-//! notation.
+//!     object notation.
 //!   - In relaxed mode, the string in the datetime object notation is RFC 3339 (ISO-8601) formatted
 //!     (if the date is after 1970).
 //!
@@ -43,6 +42,11 @@
 //! // canonical: {"x":{"$numberInt":"5"},"d":{"$date":{"$numberLong":"1591050020711"}}}
 //! ```
 //!
+//! JSON numbers cannot represent the IEEE 754 special values, so NaN and the infinities are always
+//! written in object notation, even in relaxed mode: `f64::NAN` becomes
+//! `{"$numberDouble":"NaN"}`, and `f64::INFINITY` and `f64::NEG_INFINITY` become
+//! `{"$numberDouble":"Infinity"}` and `{"$numberDouble":"-Infinity"}` respectively.
+//!
 //! Canonical mode is useful when BSON values need to be round tripped without losing any type
 //! information. Relaxed mode is more useful when debugging or logging BSON data.
 //!
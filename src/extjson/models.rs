@@ -56,9 +56,9 @@ pub(crate) struct Double {
 impl Double {
     pub(crate) fn parse(self) -> extjson::de::Result<f64> {
         match self.value.as_str() {
-            "Infinity" => Ok(std::f64::INFINITY),
-            "-Infinity" => Ok(std::f64::NEG_INFINITY),
-            "NaN" => Ok(std::f64::NAN),
+            "Infinity" => Ok(f64::INFINITY),
+            "-Infinity" => Ok(f64::NEG_INFINITY),
+            "NaN" => Ok(f64::NAN),
             other => {
                 let d: f64 = other.parse().map_err(|_| {
                     extjson::de::Error::invalid_value(
@@ -72,6 +72,24 @@ impl Double {
     }
 }
 
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Decimal128 {
+    #[serde(rename = "$numberDecimal")]
+    value: String,
+}
+
+impl Decimal128 {
+    pub(crate) fn parse(self) -> extjson::de::Result<crate::Decimal128> {
+        crate::Decimal128::parse_str(self.value.as_str()).map_err(|_| {
+            extjson::de::Error::invalid_value(
+                Unexpected::Str(self.value.as_str()),
+                &"decimal128 as a string",
+            )
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub(crate) struct ObjectId {
@@ -36,6 +36,11 @@
 //! \x00                               // 0x00 = type EOO ('end of object')
 //! ```
 //!
+//! All multi-byte numbers in BSON (lengths, integers, doubles, timestamps and datetimes) are
+//! stored in little-endian byte order, regardless of the host architecture. This crate always
+//! encodes and decodes them with explicit little-endian conversions, so the bytes it produces are
+//! identical on little- and big-endian targets.
+//!
 //! BSON is the primary data representation for [MongoDB](https://www.mongodb.com/), and this crate is used in the
 //! [`mongodb`](https://docs.rs/mongodb/latest/mongodb/) driver crate in its API and implementation.
 //!
@@ -64,6 +69,9 @@
 //! | `chrono-0_4` | Enable support for v0.4 of the [`chrono`](docs.rs/chrono/0.4) crate in the public API.              | n/a                | no      |
 //! | `uuid-0_8`   | Enable support for v0.8 of the [`uuid`](docs.rs/uuid/0.8) crate in the public API.                  | n/a                | no      |
 //! | `serde_with` | Enable [`serde_with`](docs.rs/serde_with/latest) integrations for `bson::DateTime` and `bson::Uuid` | serde_with         | no      |
+//! | `tokio`      | Enable async encoding/decoding and observing `Document` mutations with [`tokio`](docs.rs/tokio/1). | tokio              | no      |
+//! | `tokio-util` | Enable a [`tokio-util`](docs.rs/tokio-util/0.7) codec for framing streams of BSON documents.       | tokio-util         | no      |
+//! | `bytes`      | Enable encoding to and decoding from [`bytes`](docs.rs/bytes/1) buffers.                            | bytes              | no      |
 //!
 //! ## BSON values
 //!
@@ -262,35 +270,80 @@
 //!
 //! The MSRV for this crate is currently 1.53.0. This will be rarely be increased, and if it ever is,
 //! it will only happen in a minor or major version release.
+//!
+//! The `tokio-util` feature is an exception: the versions of `tokio-util` it supports require Rust
+//! 1.56 or newer.
 
-#![allow(clippy::cognitive_complexity, clippy::derive_partial_eq_without_eq)]
+#![allow(
+    clippy::cognitive_complexity,
+    clippy::derive_partial_eq_without_eq,
+    clippy::multiple_bound_locations
+)]
 #![doc(html_root_url = "https://docs.rs/bson/2.3.0")]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 #[doc(inline)]
 pub use self::{
-    bson::{Array, Binary, Bson, DbPointer, Document, JavaScriptCodeWithScope, Regex, Timestamp},
+    bson::{
+        Array, Binary, Bson, CowBson, DbPointer, Document, JavaScriptCodeWithScope, Regex,
+        Timestamp,
+    },
     datetime::DateTime,
     de::{
-        from_bson, from_bson_with_options, from_document, from_document_with_options, from_reader,
-        from_reader_utf8_lossy, from_slice, from_slice_utf8_lossy, Deserializer,
-        DeserializerOptions,
+        decode_array_from_slice, decode_document, decode_document_from_slice, from_bson,
+        from_bson_with_options, from_document, from_document_with_options, from_reader,
+        from_reader_utf8_lossy, from_slice, from_slice_seed, from_slice_utf8_lossy, Deserializer,
+        DeserializerOptions, DocumentReader,
     },
     decimal128::Decimal128,
+    document::{MAX_BSON_SIZE, MAX_ENCODED_DOCUMENT_SIZE},
     raw::{
         RawArray, RawArrayBuf, RawBinaryRef, RawBson, RawBsonRef, RawDbPointerRef, RawDocument,
         RawDocumentBuf, RawJavaScriptCodeWithScope, RawJavaScriptCodeWithScopeRef, RawRegexRef,
     },
     ser::{
-        to_bson, to_bson_with_options, to_document, to_document_with_options, to_raw_document_buf,
-        to_vec, Serializer, SerializerOptions,
+        encode_array_to_vec, encode_document, encode_document_to_vec, to_bson,
+        to_bson_with_options, to_document, to_document_with_options, to_raw_document_buf, to_vec,
+        Serializer, SerializerOptions,
     },
     uuid::{Uuid, UuidRepresentation},
 };
 
+#[cfg(feature = "tokio")]
+#[doc(inline)]
+pub use self::{de::decode_document_async, ser::encode_document_async};
+
+#[cfg(feature = "bytes")]
+#[doc(inline)]
+pub use self::{de::decode_document_from_bytes, ser::encode_document_to_bytes};
+
+/// The error returned when decoding BSON into a Rust value fails, e.g. from [`from_slice`],
+/// [`from_document`] or [`decode_document`]. This is an alias for [`de::Error`].
+///
+/// It covers malformed or truncated BSON bytes, invalid UTF-8 in strings, and BSON values that
+/// don't match the shape of the type being deserialized.
+pub type DecoderError = de::Error;
+
+/// Alias for `Result<T, DecoderError>`.
+pub type DecoderResult<T> = de::Result<T>;
+
+/// The error returned when encoding a Rust value as BSON fails, e.g. from [`to_vec`],
+/// [`to_document`] or [`encode_document`]. This is an alias for [`ser::Error`].
+///
+/// It covers I/O errors from the writer, keys and values that can't be represented in BSON (such
+/// as keys containing null bytes or unsigned integers too large for an `i64`), and documents that
+/// exceed the maximum BSON document size.
+pub type EncoderError = ser::Error;
+
+/// Alias for `Result<T, EncoderError>`.
+pub type EncoderResult<T> = ser::Result<T>;
+
 #[macro_use]
 mod macros;
 mod bson;
+#[cfg(feature = "tokio-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio-util")))]
+pub mod codec;
 pub mod datetime;
 pub mod de;
 pub mod decimal128;
@@ -203,6 +203,17 @@ macro_rules! bson {
 /// };
 /// # }
 /// ```
+///
+/// The literal is parsed when the macro is expanded, so malformed syntax such as a missing value
+/// or a misplaced `:` is reported by `cargo build`. Keys may be arbitrary expressions that
+/// evaluate to something convertible into a `String`, including values only known at runtime:
+///
+/// ```rust
+/// # use bson::doc;
+/// let field = format!("field_{}", 1);
+/// let value = doc! { (field.as_str()): 1, "nested": { field: true } };
+/// assert_eq!(value, doc! { "field_1": 1, "nested": { "field_1": true } });
+/// ```
 #[macro_export]
 macro_rules! doc {
     () => {{ $crate::Document::new() }};
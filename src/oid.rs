@@ -15,6 +15,9 @@ use rand::{thread_rng, Rng};
 
 use lazy_static::lazy_static;
 
+#[doc(inline)]
+pub use crate::serde_helpers::object_id_as_hex_string as serde_string;
+
 const TIMESTAMP_SIZE: usize = 4;
 const PROCESS_ID_SIZE: usize = 5;
 const COUNTER_SIZE: usize = 3;
@@ -98,10 +101,26 @@ impl From<[u8; 12]> for ObjectId {
     }
 }
 
+impl AsRef<[u8]> for ObjectId {
+    fn as_ref(&self) -> &[u8] {
+        &self.id
+    }
+}
+
 impl ObjectId {
     /// Generates a new [`ObjectId`], represented in bytes.
     /// See the [docs](http://www.mongodb.com/docs/manual/reference/object-id/)
     /// for more information.
+    ///
+    /// ```
+    /// use bson::oid::ObjectId;
+    ///
+    /// let a = ObjectId::new();
+    /// let b = ObjectId::new();
+    /// assert_ne!(a, b);
+    /// assert_eq!(ObjectId::parse_str(a.to_hex())?, a);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn new() -> ObjectId {
         let timestamp = ObjectId::gen_timestamp();
         let process_id = ObjectId::gen_process_id();
@@ -123,6 +142,24 @@ impl ObjectId {
         ObjectId { id: bytes }
     }
 
+    /// Creates the smallest possible [`ObjectId`] for the given number of seconds since the Unix
+    /// epoch: the timestamp bytes are set to `seconds` and all remaining bytes are zero.
+    ///
+    /// Such an `ObjectId` compares less than or equal to every `ObjectId` generated during that
+    /// second, which makes it useful as a bound when querying documents by creation time:
+    ///
+    /// ```
+    /// use bson::{doc, oid::ObjectId};
+    ///
+    /// // all documents whose `_id` was generated on or after 2021-01-01T00:00:00Z
+    /// let filter = doc! { "_id": { "$gte": ObjectId::from_datetime(1_609_459_200) } };
+    /// # let _ = filter;
+    /// ```
+    pub const fn from_datetime(seconds: u32) -> ObjectId {
+        let ts = seconds.to_be_bytes();
+        ObjectId::from_bytes([ts[0], ts[1], ts[2], ts[3], 0, 0, 0, 0, 0, 0, 0, 0])
+    }
+
     /// Creates an ObjectID using a 12-byte (24-char) hexadecimal string.
     pub fn parse_str(s: impl AsRef<str>) -> Result<ObjectId> {
         let s = s.as_ref();
@@ -167,6 +204,11 @@ impl ObjectId {
         self.id
     }
 
+    /// Returns a reference to the raw byte representation of an ObjectId, without copying it.
+    pub const fn as_bytes(&self) -> &[u8; 12] {
+        &self.id
+    }
+
     /// Convert this [`ObjectId`] to its hex string representation.
     pub fn to_hex(self) -> String {
         hex::encode(self.id)
@@ -267,7 +309,7 @@ fn test_counter_overflow_u24_max() {
 #[test]
 fn test_counter_overflow_usize_max() {
     let _guard = LOCK.run_exclusively();
-    let start = usize::max_value();
+    let start = usize::MAX;
     OID_COUNTER.store(start, Ordering::SeqCst);
     // Test counter overflows to u24_max when set to usize_max
     let oid = ObjectId::new();
@@ -266,6 +266,7 @@ impl<'a> IntoIterator for &'a RawArray {
 }
 
 /// An iterator over borrowed raw BSON array values.
+#[derive(Clone, Debug)]
 pub struct RawArrayIter<'a> {
     inner: Iter<'a>,
 }
@@ -297,7 +298,7 @@ impl<'de: 'a, 'a> Deserialize<'de> for &'a RawArray {
     }
 }
 
-impl<'a> Serialize for &'a RawArray {
+impl Serialize for &RawArray {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
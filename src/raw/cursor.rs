@@ -0,0 +1,204 @@
+use crate::{
+    de::{MIN_BSON_DOCUMENT_SIZE, MIN_BSON_STRING_SIZE, MIN_CODE_WITH_SCOPE_SIZE},
+    raw::{Error, ErrorKind, Result},
+    spec::ElementType,
+};
+
+use super::{
+    checked_add,
+    error::try_with_key,
+    i32_from_slice,
+    read_nullterminated,
+    usize_try_from_i32,
+    RawDocument,
+};
+
+/// A lazy cursor over the elements of a BSON document.
+///
+/// Unlike [`Iter`](crate::raw::Iter), which decodes every value it visits into a
+/// [`RawBsonRef`](crate::raw::RawBsonRef), a `BsonCursor` only reads as much of each element as
+/// is needed to find where it ends. Each [`BsonElement`] exposes the element type, the key and
+/// the value's undecoded bytes, so callers that only care about a handful of fields can skip the
+/// rest without paying for their decoding.
+///
+/// ```
+/// use bson::{doc, raw::BsonCursor, spec::ElementType};
+///
+/// let bytes = bson::to_vec(&doc! { "skip": "me", "n": 5 })?;
+/// let n = BsonCursor::new(&bytes)?
+///     .find(|element| matches!(element, Ok(e) if e.key() == "n"))
+///     .unwrap()?;
+/// assert_eq!(n.element_type(), ElementType::Int32);
+/// assert_eq!(n.value_bytes(), &5i32.to_le_bytes());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct BsonCursor<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+
+    /// Whether the underlying bytes are assumed to be valid or if an error has been encountered.
+    /// After an error, all subsequent iterations will return None.
+    valid: bool,
+}
+
+/// A single element of a document yielded by a [`BsonCursor`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BsonElement<'a> {
+    element_type: ElementType,
+    key: &'a str,
+    value: &'a [u8],
+}
+
+impl<'a> BsonElement<'a> {
+    /// The type of this element's value.
+    pub fn element_type(&self) -> ElementType {
+        self.element_type
+    }
+
+    /// This element's key.
+    pub fn key(&self) -> &'a str {
+        self.key
+    }
+
+    /// The encoded bytes of this element's value, in the format the BSON specification defines
+    /// for its [`element_type`](BsonElement::element_type).
+    pub fn value_bytes(&self) -> &'a [u8] {
+        self.value
+    }
+}
+
+impl<'a> BsonCursor<'a> {
+    /// Creates a cursor over the document contained in `bytes`.
+    ///
+    /// Only the document's length prefix and trailing null byte are checked here; the elements
+    /// themselves are validated as the cursor reaches them.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        let doc = RawDocument::from_bytes(bytes)?;
+        Ok(Self {
+            bytes: doc.as_bytes(),
+            offset: 4,
+            valid: true,
+        })
+    }
+
+    fn length_at(&self, offset: usize) -> Result<usize> {
+        usize_try_from_i32(i32_from_slice(
+            self.bytes.get(offset..).unwrap_or_default(),
+        )?)
+    }
+
+    /// Computes the size in bytes of a value of the given type starting at `offset`.
+    fn value_size(&self, element_type: ElementType, offset: usize) -> Result<usize> {
+        let malformed = |message: &str| {
+            Err(Error::new_without_key(ErrorKind::MalformedValue {
+                message: message.to_string(),
+            }))
+        };
+
+        let size = match element_type {
+            ElementType::Boolean => 1,
+            ElementType::Int32 => 4,
+            ElementType::Double
+            | ElementType::Int64
+            | ElementType::DateTime
+            | ElementType::Timestamp => 8,
+            ElementType::ObjectId => 12,
+            ElementType::Decimal128 => 16,
+            ElementType::Null
+            | ElementType::Undefined
+            | ElementType::MinKey
+            | ElementType::MaxKey => 0,
+            ElementType::String | ElementType::JavaScriptCode | ElementType::Symbol => {
+                let size = checked_add(self.length_at(offset)?, 4)?;
+                if size < MIN_BSON_STRING_SIZE as usize {
+                    return malformed("string length too small");
+                }
+                size
+            }
+            ElementType::DbPointer => {
+                let size = checked_add(self.length_at(offset)?, 4)?;
+                if size < MIN_BSON_STRING_SIZE as usize {
+                    return malformed("string length too small");
+                }
+                checked_add(size, 12)?
+            }
+            ElementType::EmbeddedDocument | ElementType::Array => {
+                let size = self.length_at(offset)?;
+                if size < MIN_BSON_DOCUMENT_SIZE as usize {
+                    return malformed("document too small");
+                }
+                size
+            }
+            ElementType::JavaScriptCodeWithScope => {
+                let size = self.length_at(offset)?;
+                if size < MIN_CODE_WITH_SCOPE_SIZE as usize {
+                    return malformed("code with scope length too small");
+                }
+                size
+            }
+            ElementType::Binary => checked_add(self.length_at(offset)?, 4 + 1)?,
+            ElementType::RegularExpression => {
+                let rest = self.bytes.get(offset..).unwrap_or_default();
+                let pattern = read_nullterminated(rest)?;
+                let options = read_nullterminated(&rest[pattern.len() + 1..])?;
+                pattern.len() + 1 + options.len() + 1
+            }
+        };
+        Ok(size)
+    }
+}
+
+impl<'a> Iterator for BsonCursor<'a> {
+    type Item = Result<BsonElement<'a>>;
+
+    fn next(&mut self) -> Option<Result<BsonElement<'a>>> {
+        if !self.valid {
+            return None;
+        } else if self.offset == self.bytes.len() - 1 {
+            // end of document marker; its presence was checked in `new`
+            return None;
+        }
+
+        let key = match read_nullterminated(&self.bytes[self.offset + 1..]) {
+            Ok(k) => k,
+            Err(e) => {
+                self.valid = false;
+                return Some(Err(e));
+            }
+        };
+
+        let result = try_with_key(key, || {
+            let tag = self.bytes[self.offset];
+            let element_type = ElementType::from(tag).ok_or_else(|| {
+                Error::new_without_key(ErrorKind::MalformedValue {
+                    message: format!("invalid tag: {}", tag),
+                })
+            })?;
+
+            let value_offset = self.offset + 1 + key.len() + 1; // type specifier + key + \0
+            let size = self.value_size(element_type, value_offset)?;
+            let end = checked_add(value_offset, size)?;
+
+            // the value must leave room for the document's trailing null byte
+            if end >= self.bytes.len() {
+                return Err(Error::new_without_key(ErrorKind::MalformedValue {
+                    message: format!("value length exceeds remaining length of buffer: {}", size),
+                }));
+            }
+
+            self.offset = end;
+            Ok(BsonElement {
+                element_type,
+                key,
+                value: &self.bytes[value_offset..end],
+            })
+        });
+
+        if result.is_err() {
+            self.valid = false;
+        }
+
+        Some(result)
+    }
+}
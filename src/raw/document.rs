@@ -475,6 +475,29 @@ impl RawDocument {
         self.get_with(key, ElementType::Int64, RawBsonRef::as_i64)
     }
 
+    /// Gets an iterator over the elements in the [`RawDocument`], which yields
+    /// `Result<(&str, RawBsonRef<'_>)>`.
+    ///
+    /// Elements are parsed lazily as the iterator advances, and the yielded keys and values borrow
+    /// directly from the underlying bytes, so no allocation is performed.
+    ///
+    /// ```
+    /// # use bson::raw::Error;
+    /// use bson::raw::RawDocument;
+    ///
+    /// let doc = RawDocument::from_bytes(b"\x13\x00\x00\x00\x02hi\x00\x06\x00\x00\x00y'all\x00\x00")?;
+    ///
+    /// for element in doc.iter() {
+    ///     let (key, value) = element?;
+    ///     assert_eq!(key, "hi");
+    ///     assert_eq!(value.as_str(), Some("y'all"));
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        self.into_iter()
+    }
+
     /// Return a reference to the contained data as a `&[u8]`
     ///
     /// ```
@@ -509,7 +532,7 @@ impl<'de: 'a, 'a> Deserialize<'de> for &'a RawDocument {
     }
 }
 
-impl<'a> Serialize for &'a RawDocument {
+impl Serialize for &RawDocument {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -195,8 +195,7 @@ impl RawDocumentBuf {
     /// ```
     pub fn append(&mut self, key: impl AsRef<str>, value: impl Into<RawBson>) {
         fn append_string(doc: &mut RawDocumentBuf, value: &str) {
-            doc.data
-                .extend(&((value.as_bytes().len() + 1) as i32).to_le_bytes());
+            doc.data.extend(&((value.len() + 1) as i32).to_le_bytes());
             doc.data.extend(value.as_bytes());
             doc.data.push(0);
         }
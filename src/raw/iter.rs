@@ -28,6 +28,7 @@ use super::{
 };
 
 /// An iterator over the document's entries.
+#[derive(Clone, Debug)]
 pub struct Iter<'a> {
     doc: &'a RawDocument,
     offset: usize,
@@ -93,6 +94,40 @@ impl<'a> Iter<'a> {
         }
         RawDocument::from_bytes(&self.doc.as_bytes()[starting_at..end])
     }
+
+    /// After a UTF-8 error has been returned for an element's value, moves past that element so
+    /// that iteration can resume with the next one, returning the element's key.
+    ///
+    /// This is only possible for values whose extent is given by a length prefix (strings,
+    /// JavaScript code, symbols, DB pointers and code with scope); `None` is returned otherwise,
+    /// e.g. for an invalid key or regular expression, and the iterator remains exhausted.
+    pub(crate) fn skip_invalid_utf8_value(&mut self) -> Option<&'a str> {
+        let bytes = self.doc.as_bytes();
+        let key = read_nullterminated(bytes.get(self.offset + 1..)?).ok()?;
+        let valueoffset = self.offset + 1 + key.len() + 1;
+        let len = i32_from_slice(bytes.get(valueoffset..)?).ok()?;
+        if len < 0 {
+            return None;
+        }
+
+        let len = len as usize;
+        let element_size = match ElementType::from(bytes[self.offset])? {
+            ElementType::String | ElementType::JavaScriptCode | ElementType::Symbol => 4 + len,
+            ElementType::DbPointer => 4 + len + 12,
+            ElementType::JavaScriptCodeWithScope => len,
+            _ => return None,
+        };
+
+        // the document's null terminator must still follow the skipped element.
+        let end = checked_add(valueoffset, element_size).ok()?;
+        if end >= bytes.len() {
+            return None;
+        }
+
+        self.offset = end;
+        self.valid = true;
+        Some(key)
+    }
 }
 
 impl<'a> Iterator for Iter<'a> {
@@ -3,10 +3,9 @@ mod props;
 
 use super::*;
 use crate::{
-    doc,
     oid::ObjectId,
     raw::error::ValueAccessErrorKind,
-    spec::BinarySubtype,
+    spec::{BinarySubtype, ElementType},
     Binary,
     Bson,
     DateTime,
@@ -462,6 +461,65 @@ fn into_bson_conversion() {
     );
 }
 
+#[test]
+fn cursor() {
+    let bytes = crate::to_vec(&doc! {
+        "string": "hello",
+        "int": 5,
+        "document": { "nested": true },
+        "regex": Regex { pattern: "a+".into(), options: "i".into() },
+        "null": null,
+        "last": 2.5,
+    })
+    .unwrap();
+
+    let elements: Vec<BsonElement> = BsonCursor::new(&bytes)
+        .unwrap()
+        .collect::<Result<_>>()
+        .unwrap();
+    let keys: Vec<_> = elements
+        .iter()
+        .map(|e| (e.key(), e.element_type()))
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            ("string", ElementType::String),
+            ("int", ElementType::Int32),
+            ("document", ElementType::EmbeddedDocument),
+            ("regex", ElementType::RegularExpression),
+            ("null", ElementType::Null),
+            ("last", ElementType::Double),
+        ]
+    );
+    assert_eq!(elements[0].value_bytes(), b"\x06\0\0\0hello\0");
+    assert_eq!(elements[1].value_bytes(), &5i32.to_le_bytes());
+    assert_eq!(
+        RawDocument::from_bytes(elements[2].value_bytes()).unwrap(),
+        rawdoc! { "nested": true }.as_ref()
+    );
+    assert_eq!(elements[3].value_bytes(), b"a+\0i\0");
+    assert_eq!(elements[4].value_bytes(), b"");
+    assert_eq!(elements[5].value_bytes(), &2.5f64.to_le_bytes());
+
+    // a string length that runs past the end of the document
+    let mut truncated = crate::to_vec(&doc! { "a": "b", "c": 1 }).unwrap();
+    truncated[7] = 0x7f;
+    let mut cursor = BsonCursor::new(&truncated).unwrap();
+    let error = cursor.next().unwrap().unwrap_err();
+    assert_eq!(error.key(), Some("a"));
+    assert!(cursor.next().is_none());
+
+    // an unknown element type
+    let mut invalid = crate::to_vec(&doc! { "a": 1 }).unwrap();
+    invalid[4] = 0x42;
+    let mut cursor = BsonCursor::new(&invalid).unwrap();
+    assert!(cursor.next().unwrap().is_err());
+    assert!(cursor.next().is_none());
+
+    assert!(BsonCursor::new(b"\x05\0\0").is_err());
+}
+
 use props::arbitrary_bson;
 use proptest::prelude::*;
 use std::convert::TryInto;
@@ -472,6 +530,29 @@ proptest! {
         let _ = RawDocumentBuf::from_bytes(s);
     }
 
+    #[test]
+    fn cursor_no_crashes(s: Vec<u8>) {
+        if let Ok(cursor) = BsonCursor::new(&s) {
+            let _ = cursor.count();
+        }
+    }
+
+    #[test]
+    fn cursor_matches_iter(bson in arbitrary_bson()) {
+        let raw = RawDocumentBuf::from_document(&doc! { "bson": bson, "after": 1 }).unwrap();
+        let from_cursor: Vec<_> = BsonCursor::new(raw.as_bytes())
+            .unwrap()
+            .map(|e| e.map(|e| (e.key(), e.element_type())))
+            .collect::<Result<_>>()
+            .unwrap();
+        let from_iter: Vec<_> = raw
+            .iter()
+            .map(|e| e.map(|(key, value)| (key, value.element_type())))
+            .collect::<Result<_>>()
+            .unwrap();
+        prop_assert_eq!(from_cursor, from_iter);
+    }
+
     #[test]
     fn roundtrip_bson(bson in arbitrary_bson()) {
         let doc = doc! { "bson": bson };
@@ -5,6 +5,13 @@ use serde::ser;
 use crate::bson::Bson;
 
 /// Possible errors that can arise during encoding.
+///
+/// This enum is `#[non_exhaustive]`, so new variants may be added in minor releases; code that
+/// matches on it needs a wildcard arm. The variants describing specific, structured failures
+/// (`Io`, `InvalidKey`, `InvalidCString`, `UnsignedIntegerExceededRange` and
+/// `DocumentTooLarge`) are stable to match on. `SerializationError` wraps errors raised through
+/// [`serde::ser::Error::custom`], whose messages are not a stable format and should only be
+/// displayed.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum Error {
@@ -14,9 +21,29 @@ pub enum Error {
     /// A key could not be serialized to a BSON string.
     InvalidDocumentKey(Bson),
 
-    /// An invalid string was specified.
+    /// A regular expression's pattern or options, which must be encoded as BSON C strings,
+    /// contained a null byte.
+    ///
+    /// A C string is terminated by its first null byte, so the value could not be read back
+    /// intact. Ordinary string values are length-prefixed and may contain null bytes.
     InvalidCString(String),
 
+    /// A document key could not be encoded.
+    ///
+    /// The only characters the BSON format itself disallows in keys are null bytes, since a key
+    /// is terminated by its first null byte. Whether keys may start with `$` or contain `.`
+    /// depends on the MongoDB server version and on where the document is used (e.g. inserted
+    /// documents versus update operators), so those are not rejected during encoding; use
+    /// [`validate_document`](crate::document::validate_document) to check for them.
+    #[non_exhaustive]
+    InvalidKey {
+        /// The key that could not be encoded.
+        key: String,
+
+        /// Why the key is invalid.
+        reason: &'static str,
+    },
+
     /// A general error that occurred during serialization.
     /// See: <https://docs.rs/serde/1.0.110/serde/ser/trait.Error.html#tymethod.custom>
     #[non_exhaustive]
@@ -27,6 +54,16 @@ pub enum Error {
 
     /// An unsigned integer type could not fit into a signed integer type.
     UnsignedIntegerExceededRange(u64),
+
+    /// The encoded size of a document exceeded the maximum allowed size.
+    #[non_exhaustive]
+    DocumentTooLarge {
+        /// The encoded size of the document, in bytes.
+        actual: usize,
+
+        /// The maximum allowed size, in bytes.
+        limit: usize,
+    },
 }
 
 impl From<io::Error> for Error {
@@ -43,6 +80,9 @@ impl fmt::Display for Error {
             Error::InvalidCString(ref string) => {
                 write!(fmt, "cstrings cannot contain null bytes: {:?}", string)
             }
+            Error::InvalidKey { ref key, reason } => {
+                write!(fmt, "invalid key {:?}: {}", key, reason)
+            }
             Error::SerializationError { ref message } => message.fmt(fmt),
             Error::UnsignedIntegerExceededRange(value) => write!(
                 fmt,
@@ -51,12 +91,17 @@ impl fmt::Display for Error {
                  size.",
                 value
             ),
+            Error::DocumentTooLarge { actual, limit } => write!(
+                fmt,
+                "document of {} bytes exceeds the maximum size of {} bytes",
+                actual, limit
+            ),
         }
     }
 }
 
 impl error::Error for Error {
-    fn cause(&self) -> Option<&dyn error::Error> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
             Error::Io(ref inner) => Some(inner.as_ref()),
             _ => None,
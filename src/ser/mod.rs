@@ -35,6 +35,7 @@ use std::{io::Write, iter::FromIterator, mem};
 use crate::{
     bson::{Bson, DbPointer, Document, JavaScriptCodeWithScope, Regex},
     de::MAX_BSON_SIZE,
+    document::MAX_ENCODED_DOCUMENT_SIZE,
     spec::BinarySubtype,
     Binary,
     RawDocumentBuf,
@@ -57,6 +58,17 @@ fn write_cstring<W: Write + ?Sized>(writer: &mut W, s: &str) -> Result<()> {
     Ok(())
 }
 
+/// Writes a document key, which is encoded as a C string.
+pub(crate) fn write_key<W: Write + ?Sized>(writer: &mut W, key: &str) -> Result<()> {
+    if key.contains('\0') {
+        return Err(Error::InvalidKey {
+            key: key.into(),
+            reason: "keys cannot contain null bytes",
+        });
+    }
+    write_cstring(writer, key)
+}
+
 #[inline]
 pub(crate) fn write_i32<W: Write + ?Sized>(writer: &mut W, val: i32) -> Result<()> {
     writer
@@ -127,13 +139,13 @@ pub(crate) fn serialize_bson<W: Write + ?Sized>(
     val: &Bson,
 ) -> Result<()> {
     writer.write_all(&[val.element_type() as u8])?;
-    write_cstring(writer, key)?;
+    write_key(writer, key)?;
 
     match *val {
         Bson::Double(v) => write_f64(writer, v),
         Bson::String(ref v) => write_string(writer, v),
         Bson::Array(ref v) => serialize_array(writer, v),
-        Bson::Document(ref v) => v.to_writer(writer),
+        Bson::Document(ref v) => v.write_without_size_check(writer),
         Bson::Boolean(v) => writer
             .write_all(&[if v { 0x01 } else { 0x00 }])
             .map_err(From::from),
@@ -156,7 +168,7 @@ pub(crate) fn serialize_bson<W: Write + ?Sized>(
         }) => {
             let mut buf = Vec::new();
             write_string(&mut buf, code)?;
-            scope.to_writer(&mut buf)?;
+            scope.write_without_size_check(&mut buf)?;
 
             write_i32(writer, buf.len() as i32 + 4)?;
             writer.write_all(&buf).map_err(From::from)
@@ -193,6 +205,14 @@ pub(crate) fn serialize_bson<W: Write + ?Sized>(
 /// the format is human readable or not. To serialize to a [`Document`] with a serializer that
 /// presents itself as not human readable, use [`to_bson_with_options`] with
 /// [`SerializerOptions::human_readable`] set to false.
+///
+/// ```
+/// use bson::{bson, oid::ObjectId};
+///
+/// let oid = ObjectId::new();
+/// assert_eq!(bson::to_bson(&(oid, vec![1, 2]))?, bson!([oid, [1, 2]]));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn to_bson<T: ?Sized>(value: &T) -> Result<Bson>
 where
     T: Serialize,
@@ -233,6 +253,20 @@ where
 /// the format is human readable or not. To serialize to a [`Document`] with a serializer that
 /// presents itself as not human readable, use [`to_document_with_options`] with
 /// [`SerializerOptions::human_readable`] set to false.
+///
+/// ```
+/// use bson::doc;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_eq!(bson::to_document(&Point { x: 1, y: 2 })?, doc! { "x": 1, "y": 2 });
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn to_document<T: ?Sized>(value: &T) -> Result<Document>
 where
     T: Serialize,
@@ -274,7 +308,114 @@ where
     }
 }
 
+/// Encode the given [`Document`] as BSON and write it to the provided I/O stream.
+///
+/// This is equivalent to [`Document::to_writer`], but borrows the writer rather than taking it
+/// by value.
+///
+/// ```
+/// use bson::{doc, encode_document};
+///
+/// let mut bytes = Vec::new();
+/// encode_document(&mut bytes, &doc! { "x": 1 })?;
+/// assert_eq!(bytes, bson::to_vec(&doc! { "x": 1 })?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn encode_document<W: Write + ?Sized>(writer: &mut W, doc: &Document) -> Result<()> {
+    doc.to_writer(writer)
+}
+
+/// Encode the given [`Document`] as a BSON byte vector.
+///
+/// ```
+/// use bson::{doc, decode_document_from_slice, encode_document_to_vec};
+///
+/// let doc = doc! { "x": 1 };
+/// let bytes = encode_document_to_vec(&doc)?;
+/// assert_eq!(bytes.len(), 12);
+/// assert_eq!(decode_document_from_slice(&bytes)?, doc);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn encode_document_to_vec(doc: &Document) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    encode_document(&mut bytes, doc)?;
+    Ok(bytes)
+}
+
+/// Encode the given values as a standalone BSON array.
+///
+/// A BSON array is encoded the same way as a document whose keys are the indexes of its elements
+/// (`"0"`, `"1"`, ...), so the result can also be read back as a [`Document`].
+///
+/// ```
+/// use bson::{bson, decode_array_from_slice, encode_array_to_vec};
+///
+/// let items = vec![bson!(1), bson!("two")];
+/// let bytes = encode_array_to_vec(&items)?;
+/// assert_eq!(decode_array_from_slice(&bytes)?, items);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn encode_array_to_vec(items: &[Bson]) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    serialize_array(&mut bytes, items)?;
+    Ok(bytes)
+}
+
+/// Encode the given [`Document`] as BSON into a [`bytes::Bytes`] buffer.
+///
+/// The document is encoded as by [`encode_document_to_vec`], and the resulting buffer is then
+/// handed over to [`bytes::Bytes`] without being copied.
+///
+/// ```
+/// use bson::{doc, decode_document_from_bytes, encode_document_to_bytes};
+///
+/// let doc = doc! { "x": 1 };
+/// let bytes = encode_document_to_bytes(&doc)?;
+/// assert_eq!(bytes.len(), 12);
+/// assert_eq!(decode_document_from_bytes(bytes)?, doc);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub fn encode_document_to_bytes(doc: &Document) -> Result<bytes::Bytes> {
+    encode_document_to_vec(doc).map(bytes::Bytes::from)
+}
+
+/// Encode the given [`Document`] as BSON and write it to the provided asynchronous stream.
+///
+/// The document is encoded into memory first and then written with a single
+/// [`write_all`](tokio::io::AsyncWriteExt::write_all). The writer is not flushed.
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub async fn encode_document_async<W>(writer: &mut W, doc: &Document) -> Result<()>
+where
+    W: tokio::io::AsyncWrite + Unpin + ?Sized,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let bytes = encode_document_to_vec(doc)?;
+    writer.write_all(&bytes).await?;
+    Ok(())
+}
+
 /// Serialize the given `T` as a BSON byte vector.
+///
+/// `T` must serialize as a document (e.g. a struct or a map), since a document is the only
+/// top-level value the BSON format allows.
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let bytes = bson::to_vec(&Point { x: 1, y: 2 })?;
+/// assert_eq!(bson::from_slice::<bson::Document>(&bytes)?, bson::doc! { "x": 1, "y": 2 });
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 #[inline]
 pub fn to_vec<T>(value: &T) -> Result<Vec<u8>>
 where
@@ -282,7 +423,14 @@ where
 {
     let mut serializer = raw::Serializer::new();
     value.serialize(&mut serializer)?;
-    Ok(serializer.into_vec())
+    let bytes = serializer.into_vec();
+    if bytes.len() > MAX_ENCODED_DOCUMENT_SIZE {
+        return Err(Error::DocumentTooLarge {
+            actual: bytes.len(),
+            limit: MAX_ENCODED_DOCUMENT_SIZE,
+        });
+    }
+    Ok(bytes)
 }
 
 /// Serialize the given `T` as a [`RawDocumentBuf`].
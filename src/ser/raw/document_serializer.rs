@@ -1,7 +1,7 @@
 use serde::{ser::Impossible, Serialize};
 
 use crate::{
-    ser::{write_cstring, write_i32, Error, Result},
+    ser::{write_i32, write_key, Error, Result},
     to_bson,
     Bson,
 };
@@ -245,7 +245,7 @@ impl<'a> serde::Serializer for KeySerializer<'a> {
 
     #[inline]
     fn serialize_str(self, v: &str) -> Result<Self::Ok> {
-        write_cstring(&mut self.root_serializer.bytes, v)
+        write_key(&mut self.root_serializer.bytes, v)
     }
 
     #[inline]
@@ -10,7 +10,7 @@ use serde::{
 
 use self::value_serializer::{ValueSerializer, ValueType};
 
-use super::{write_binary, write_cstring, write_f64, write_i32, write_i64, write_string};
+use super::{write_binary, write_f64, write_i32, write_i64, write_key, write_string};
 use crate::{
     raw::{RAW_ARRAY_NEWTYPE, RAW_DOCUMENT_NEWTYPE},
     ser::{Error, Result},
@@ -259,11 +259,7 @@ impl<'a> serde::Serializer for &'a mut Serializer {
     }
 
     #[inline]
-    fn serialize_newtype_struct<T: ?Sized>(
-        mut self,
-        name: &'static str,
-        value: &T,
-    ) -> Result<Self::Ok>
+    fn serialize_newtype_struct<T: ?Sized>(self, name: &'static str, value: &T) -> Result<Self::Ok>
     where
         T: serde::Serialize,
     {
@@ -443,7 +439,7 @@ impl<'a> VariantSerializer<'a> {
             VariantInnerType::Tuple => ElementType::Array,
         };
         rs.bytes.push(inner as u8);
-        write_cstring(&mut rs.bytes, variant)?;
+        write_key(&mut rs.bytes, variant)?;
         let inner_start = rs.bytes.len();
         // write placeholder length for inner, will be updated at end
         write_i32(&mut rs.bytes, 0)?;
@@ -462,7 +458,7 @@ impl<'a> VariantSerializer<'a> {
         T: Serialize + ?Sized,
     {
         self.root_serializer.reserve_element_type();
-        write_cstring(&mut self.root_serializer.bytes, k)?;
+        write_key(&mut self.root_serializer.bytes, k)?;
         v.serialize(&mut *self.root_serializer)?;
 
         self.num_elements_serialized += 1;
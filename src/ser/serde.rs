@@ -69,6 +69,9 @@ impl Serialize for Bson {
             Bson::Int64(v) => serializer.serialize_i64(*v),
             Bson::ObjectId(oid) => oid.serialize(serializer),
             Bson::DateTime(dt) => dt.serialize(serializer),
+            Bson::Binary(_) | Bson::Decimal128(_) if serializer.is_human_readable() => {
+                serializer.serialize_newtype_struct(EXTJSON_NEWTYPE, &ExtJson(self))
+            }
             Bson::Binary(b) => b.serialize(serializer),
             Bson::JavaScriptCode(c) => {
                 let mut state = serializer.serialize_struct("$code", 1)?;
@@ -108,6 +111,52 @@ impl Serialize for Bson {
     }
 }
 
+/// The newtype name `Bson` uses to wrap the extended JSON form of binary and decimal values for
+/// human-readable serializers. [`Serializer`] produces BSON rather than JSON, so it recognizes this
+/// name and converts the value directly instead.
+const EXTJSON_NEWTYPE: &str = "$__bson_private_extjson";
+
+/// Serializes a binary or decimal [`Bson`] value as extended JSON when the serializer is human
+/// readable.
+struct ExtJson<'a>(&'a Bson);
+
+impl Serialize for ExtJson<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if !serializer.is_human_readable() {
+            return self.0.serialize(serializer);
+        }
+        match self.0 {
+            // Human-readable formats such as JSON would otherwise write generic binary as a plain
+            // list of bytes, so always use the `$binary` form for them.
+            Bson::Binary(b) => {
+                let mut state = serializer.serialize_struct("$binary", 1)?;
+                let body = extjson::models::BinaryBody {
+                    base64: base64::encode(b.bytes.as_slice()),
+                    subtype: hex::encode([b.subtype.into()]),
+                };
+                state.serialize_field("$binary", &body)?;
+                state.end()
+            }
+            // The string form is what extended JSON uses, but it doesn't preserve every bit
+            // pattern (e.g. the sign of a NaN), so fall back to the bytes when it wouldn't parse
+            // back to the same value.
+            Bson::Decimal128(d) => {
+                let s = d.to_spec_string();
+                if Decimal128::parse_str(&s).ok().as_ref() != Some(d) {
+                    return self.0.serialize(serializer);
+                }
+                let mut state = serializer.serialize_struct("$numberDecimal", 1)?;
+                state.serialize_field("$numberDecimal", &s)?;
+                state.end()
+            }
+            other => other.serialize(serializer),
+        }
+    }
+}
+
 /// Serde Serializer
 #[non_exhaustive]
 pub struct Serializer {
@@ -133,6 +182,7 @@ impl SerializerOptions {
 }
 
 /// A builder used to construct new [`SerializerOptions`] structs.
+#[derive(Clone, Debug)]
 pub struct SerializerOptionsBuilder {
     options: SerializerOptions,
 }
@@ -152,6 +202,15 @@ impl SerializerOptionsBuilder {
 
 impl Serializer {
     /// Construct a new `Serializer`.
+    ///
+    /// ```
+    /// use bson::{bson, Serializer};
+    /// use serde::Serialize;
+    ///
+    /// let bson = (1, "two").serialize(Serializer::new())?;
+    /// assert_eq!(bson, bson!([1, "two"]));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     #[allow(clippy::new_without_default)]
     pub fn new() -> Serializer {
         Serializer {
@@ -348,6 +407,11 @@ impl ser::Serializer for Serializer {
                     b
                 ))),
             },
+            EXTJSON_NEWTYPE => {
+                let mut options = self.options;
+                options.human_readable = Some(false);
+                value.serialize(Serializer::new_with_options(options))
+            }
             _ => value.serialize(self),
         }
     }
@@ -6,6 +6,16 @@ use serde::{ser, Serialize, Serializer};
 
 use crate::oid::ObjectId;
 
+#[doc(inline)]
+pub use binary_as_base64_string::{
+    deserialize as deserialize_binary_from_base64_string,
+    serialize as serialize_binary_as_base64_string,
+};
+#[doc(inline)]
+pub use bson_datetime_as_millis::{
+    deserialize as deserialize_bson_datetime_from_millis,
+    serialize as serialize_bson_datetime_as_millis,
+};
 #[doc(inline)]
 pub use bson_datetime_as_rfc3339_string::{
     deserialize as deserialize_bson_datetime_from_rfc3339_string,
@@ -23,6 +33,8 @@ pub use hex_string_as_object_id::{
     serialize as serialize_hex_string_as_object_id,
 };
 #[doc(inline)]
+pub use object_id_as_hex_string::deserialize as deserialize_object_id_from_hex_string;
+#[doc(inline)]
 pub use rfc3339_string_as_bson_datetime::{
     deserialize as deserialize_rfc3339_string_from_bson_datetime,
     serialize as serialize_rfc3339_string_as_bson_datetime,
@@ -338,7 +350,8 @@ pub mod rfc3339_string_as_bson_datetime {
 }
 
 /// Contains functions to serialize a [`crate::DateTime`] as an RFC 3339 (ISO 8601) formatted string
-/// and deserialize a [`crate::DateTime`] from an RFC 3339 (ISO 8601) formatted string.
+/// and deserialize a [`crate::DateTime`] from an RFC 3339 (ISO 8601) formatted string. This is also
+/// available as [`crate::datetime::serde_rfc3339`].
 ///
 /// ```rust
 /// # use serde::{Serialize, Deserialize};
@@ -375,6 +388,127 @@ pub mod bson_datetime_as_rfc3339_string {
     }
 }
 
+/// Contains functions to serialize a [`crate::Binary`] as a base64 encoded string using the
+/// standard alphabet and deserialize a [`crate::Binary`] from such a string. The
+/// [`url_safe`](binary_as_base64_string::url_safe) submodule does the same using the URL-safe
+/// alphabet.
+///
+/// The binary subtype is not included in the string, so deserialized values always have the
+/// [`BinarySubtype::Generic`](crate::spec::BinarySubtype::Generic) subtype.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{serde_helpers::binary_as_base64_string, spec::BinarySubtype, Binary};
+/// #[derive(Serialize, Deserialize)]
+/// struct Attachment {
+///     #[serde(with = "binary_as_base64_string")]
+///     pub contents: Binary,
+///     #[serde(with = "binary_as_base64_string::url_safe")]
+///     pub token: Binary,
+/// }
+///
+/// let attachment = Attachment {
+///     contents: Binary { subtype: BinarySubtype::Generic, bytes: vec![0xfb, 0xff] },
+///     token: Binary { subtype: BinarySubtype::Generic, bytes: vec![0xfb, 0xff] },
+/// };
+/// let json = serde_json::to_string(&attachment).unwrap();
+/// assert_eq!(json, r#"{"contents":"+/8=","token":"-_8="}"#);
+/// ```
+pub mod binary_as_base64_string {
+    use crate::{spec::BinarySubtype, Binary};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::result::Result;
+
+    /// Deserializes a [`crate::Binary`] from a base64 string using the standard alphabet.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Binary, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_with_config(deserializer, base64::STANDARD)
+    }
+
+    /// Serializes a [`crate::Binary`] as a base64 string using the standard alphabet.
+    pub fn serialize<S: Serializer>(val: &Binary, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base64::encode_config(&val.bytes, base64::STANDARD))
+    }
+
+    fn deserialize_with_config<'de, D>(
+        deserializer: D,
+        config: base64::Config,
+    ) -> Result<Binary, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+        let bytes = base64::decode_config(&encoded, config).map_err(|e| {
+            de::Error::custom(format!("cannot decode base64 from \"{}\": {}", encoded, e))
+        })?;
+        Ok(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes,
+        })
+    }
+
+    /// Contains functions to serialize a [`crate::Binary`] as a base64 encoded string using the
+    /// URL-safe alphabet and deserialize a [`crate::Binary`] from such a string.
+    pub mod url_safe {
+        use crate::Binary;
+        use serde::{Deserializer, Serializer};
+        use std::result::Result;
+
+        /// Deserializes a [`crate::Binary`] from a base64 string using the URL-safe alphabet.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Binary, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize_with_config(deserializer, base64::URL_SAFE)
+        }
+
+        /// Serializes a [`crate::Binary`] as a base64 string using the URL-safe alphabet.
+        pub fn serialize<S: Serializer>(val: &Binary, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&base64::encode_config(&val.bytes, base64::URL_SAFE))
+        }
+    }
+}
+
+/// Contains functions to serialize a [`crate::DateTime`] as an `i64` number of milliseconds since
+/// the Unix epoch and deserialize a [`crate::DateTime`] from such a number. This is also available
+/// as [`crate::datetime::serde_as_millis`].
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::serde_helpers::bson_datetime_as_millis;
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "bson_datetime_as_millis")]
+///     pub date: bson::DateTime,
+/// }
+///
+/// let event = Event { date: bson::DateTime::from_millis(1_500_000_000_000) };
+/// let json = serde_json::to_string(&event).unwrap();
+/// assert_eq!(json, r#"{"date":1500000000000}"#);
+/// ```
+pub mod bson_datetime_as_millis {
+    use crate::DateTime;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    /// Deserializes a [`crate::DateTime`] from an `i64` number of milliseconds since the Unix
+    /// epoch.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let millis = i64::deserialize(deserializer)?;
+        Ok(DateTime::from_millis(millis))
+    }
+
+    /// Serializes a [`crate::DateTime`] as an `i64` number of milliseconds since the Unix epoch.
+    pub fn serialize<S: Serializer>(val: &DateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        val.timestamp_millis().serialize(serializer)
+    }
+}
+
 /// Contains functions to serialize a hex string as an ObjectId and deserialize a
 /// hex string from an ObjectId
 ///
@@ -412,6 +546,45 @@ pub mod hex_string_as_object_id {
     }
 }
 
+/// Contains functions to serialize an [`ObjectId`] as a plain 24-character hex string and
+/// deserialize an [`ObjectId`] from such a string, rather than using the BSON ObjectId type or its
+/// `{ "$oid": <hex> }` extended JSON form. This is also available as [`crate::oid::serde_string`].
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{oid::ObjectId, serde_helpers::object_id_as_hex_string};
+/// #[derive(Serialize, Deserialize)]
+/// struct Item {
+///     #[serde(with = "object_id_as_hex_string")]
+///     pub id: ObjectId,
+/// }
+///
+/// let item = Item { id: ObjectId::parse_str("507f1f77bcf86cd799439011").unwrap() };
+/// let json = serde_json::to_string(&item).unwrap();
+/// assert_eq!(json, r#"{"id":"507f1f77bcf86cd799439011"}"#);
+/// ```
+pub mod object_id_as_hex_string {
+    use crate::oid::ObjectId;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::result::Result;
+
+    /// Deserializes an [`ObjectId`] from a hex string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ObjectId, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+        ObjectId::parse_str(&hex).map_err(|_| {
+            de::Error::invalid_value(de::Unexpected::Str(&hex), &"a 24-character hex string")
+        })
+    }
+
+    /// Serializes an [`ObjectId`] as a hex string.
+    pub fn serialize<S: Serializer>(val: &ObjectId, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_object_id_as_hex_string(val, serializer)
+    }
+}
+
 #[allow(unused_macros)]
 macro_rules! as_binary_mod {
     ($feat:meta, $uu:path) => {
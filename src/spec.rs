@@ -105,7 +105,9 @@ pub enum ElementType {
     Int64 = ELEMENT_TYPE_64BIT_INTEGER,
     /// [128-bit decimal floating point](https://github.com/mongodb/specifications/blob/master/source/bson-decimal128/decimal128.rst)
     Decimal128 = ELEMENT_TYPE_128BIT_DECIMAL,
+    /// Max key
     MaxKey = ELEMENT_TYPE_MAXKEY,
+    /// Min key
     MinKey = ELEMENT_TYPE_MINKEY,
 }
 
@@ -139,6 +141,73 @@ impl ElementType {
             _ => return None,
         })
     }
+
+    /// Attempt to convert from a `u8`. This is equivalent to [`ElementType::from`].
+    #[inline]
+    pub fn from_u8(tag: u8) -> Option<ElementType> {
+        Self::from(tag)
+    }
+
+    /// Returns the tag byte used to identify this element type in encoded BSON.
+    #[inline]
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// The tag bytes identifying each BSON element type, for use where a plain `u8` is needed (e.g.
+/// as `match` patterns when parsing raw BSON by hand).
+///
+/// Each constant is the same byte as the corresponding [`ElementType`] variant:
+///
+/// ```
+/// use bson::spec::{element_type, ElementType};
+///
+/// assert_eq!(element_type::INT32, ElementType::Int32.as_u8());
+/// ```
+pub mod element_type {
+    /// 64-bit binary floating point
+    pub const DOUBLE: u8 = super::ELEMENT_TYPE_FLOATING_POINT;
+    /// UTF-8 string
+    pub const STRING: u8 = super::ELEMENT_TYPE_UTF8_STRING;
+    /// Embedded document
+    pub const EMBEDDED_DOCUMENT: u8 = super::ELEMENT_TYPE_EMBEDDED_DOCUMENT;
+    /// Array
+    pub const ARRAY: u8 = super::ELEMENT_TYPE_ARRAY;
+    /// Binary data
+    pub const BINARY: u8 = super::ELEMENT_TYPE_BINARY;
+    /// Deprecated. Undefined (value)
+    pub const UNDEFINED: u8 = super::ELEMENT_TYPE_UNDEFINED;
+    /// ObjectId
+    pub const OBJECT_ID: u8 = super::ELEMENT_TYPE_OBJECT_ID;
+    /// Bool value
+    pub const BOOLEAN: u8 = super::ELEMENT_TYPE_BOOLEAN;
+    /// UTC datetime
+    pub const DATETIME: u8 = super::ELEMENT_TYPE_UTC_DATETIME;
+    /// Null value
+    pub const NULL: u8 = super::ELEMENT_TYPE_NULL_VALUE;
+    /// Regular expression
+    pub const REGULAR_EXPRESSION: u8 = super::ELEMENT_TYPE_REGULAR_EXPRESSION;
+    /// Deprecated. DBPointer
+    pub const DB_POINTER: u8 = super::ELEMENT_TYPE_DBPOINTER;
+    /// JavaScript code
+    pub const JAVASCRIPT_CODE: u8 = super::ELEMENT_TYPE_JAVASCRIPT_CODE;
+    /// Deprecated. Symbol
+    pub const SYMBOL: u8 = super::ELEMENT_TYPE_SYMBOL;
+    /// JavaScript code w/ scope
+    pub const JAVASCRIPT_CODE_WITH_SCOPE: u8 = super::ELEMENT_TYPE_JAVASCRIPT_CODE_WITH_SCOPE;
+    /// 32-bit integer
+    pub const INT32: u8 = super::ELEMENT_TYPE_32BIT_INTEGER;
+    /// Timestamp
+    pub const TIMESTAMP: u8 = super::ELEMENT_TYPE_TIMESTAMP;
+    /// 64-bit integer
+    pub const INT64: u8 = super::ELEMENT_TYPE_64BIT_INTEGER;
+    /// 128-bit decimal floating point
+    pub const DECIMAL128: u8 = super::ELEMENT_TYPE_128BIT_DECIMAL;
+    /// Min key
+    pub const MIN_KEY: u8 = super::ELEMENT_TYPE_MINKEY;
+    /// Max key
+    pub const MAX_KEY: u8 = super::ELEMENT_TYPE_MAXKEY;
 }
 
 /// The available binary subtypes, plus a user-defined slot.
@@ -1,16 +1,19 @@
 use std::{
+    borrow::Cow,
     convert::TryFrom,
     time::{Duration, SystemTime},
 };
 
 use crate::{
-    doc,
     oid::ObjectId,
-    spec::BinarySubtype,
+    spec::{element_type, BinarySubtype, ElementType},
     tests::LOCK,
     Binary,
     Bson,
+    CowBson,
     DateTime,
+    DbPointer,
+    Decimal128,
     Document,
     JavaScriptCodeWithScope,
     Regex,
@@ -54,6 +57,523 @@ fn to_json() {
     assert_eq!(alphanumeric.as_str().unwrap(), "bar");
 }
 
+#[test]
+fn to_json_string() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! {
+        "_id": ObjectId::from_bytes(*b"abcdefghijkl"),
+        "date": DateTime::from_millis(0),
+        "bin": Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2, 3] },
+        "nested": { "s": "quote\"d", "n": Bson::Null },
+    };
+
+    let expected = json!({
+        "_id": { "$oid": "6162636465666768696a6b6c" },
+        "date": { "$date": "1970-01-01T00:00:00Z" },
+        "bin": { "$binary": { "base64": "AQID", "subType": "00" } },
+        "nested": { "s": "quote\"d", "n": null },
+    });
+
+    let rendered = doc.to_json_string();
+    assert_eq!(serde_json::from_str::<Value>(&rendered).unwrap(), expected);
+    assert_eq!(Bson::Document(doc).to_json_string(), rendered);
+    assert_eq!(Bson::String("x".into()).to_json_string(), "\"x\"");
+}
+
+#[test]
+fn to_canonical_extjson() {
+    let _guard = LOCK.run_concurrently();
+    // 1.23 encoded as coefficient 123 with exponent -2.
+    let mut bytes = [0u8; 16];
+    bytes[0] = 123;
+    bytes[14] = 0x3c;
+    bytes[15] = 0x30;
+
+    let doc = doc! {
+        "i": 1,
+        "l": 2_i64,
+        "d": -0.0,
+        "dec": Decimal128::from_bytes(bytes),
+        "arr": [DateTime::from_millis(-1)],
+    };
+    let expected = json!({
+        "i": { "$numberInt": "1" },
+        "l": { "$numberLong": "2" },
+        "d": { "$numberDouble": "-0.0" },
+        "dec": { "$numberDecimal": "1.23" },
+        "arr": [{ "$date": { "$numberLong": "-1" } }],
+    });
+
+    assert_eq!(doc.to_canonical_extjson(), expected);
+    assert_eq!(Bson::Document(doc).to_canonical_extjson(), expected);
+}
+
+#[test]
+fn json_value_conversions() {
+    let _guard = LOCK.run_concurrently();
+    let value = json!({
+        "small": 1,
+        "negative": i32::MIN,
+        "wide": i64::from(i32::MAX) + 1,
+        "unsigned": u64::MAX,
+        "float": 2.5,
+        "list": [true, null, "s"],
+    });
+
+    let bson = Bson::try_from(value.clone()).unwrap();
+    let doc = bson.as_document().unwrap();
+    assert_eq!(doc.get("small"), Some(&Bson::Int32(1)));
+    assert_eq!(doc.get("negative"), Some(&Bson::Int32(i32::MIN)));
+    assert_eq!(doc.get("wide"), Some(&Bson::Int64(i64::from(i32::MAX) + 1)));
+    assert_eq!(doc.get("unsigned"), Some(&Bson::Double(u64::MAX as f64)));
+    assert_eq!(doc.get("float"), Some(&Bson::Double(2.5)));
+    assert_eq!(
+        doc.get("list"),
+        Some(&Bson::Array(vec![
+            Bson::Boolean(true),
+            Bson::Null,
+            Bson::String("s".into())
+        ]))
+    );
+
+    // BSON-specific types are converted to relaxed extended JSON.
+    let oid = ObjectId::from_bytes(*b"abcdefghijkl");
+    assert_eq!(
+        Value::from(Bson::ObjectId(oid)),
+        json!({ "$oid": oid.to_hex() })
+    );
+    assert_eq!(Value::from(Bson::Int64(5)), json!(5));
+}
+
+#[test]
+fn float_specials() {
+    let _guard = LOCK.run_concurrently();
+    for (value, repr) in [
+        (f64::NAN, "NaN"),
+        (f64::INFINITY, "Infinity"),
+        (f64::NEG_INFINITY, "-Infinity"),
+    ] {
+        let bson = Bson::Double(value);
+        assert_eq!(bson.is_nan(), value.is_nan());
+        assert_eq!(bson.is_infinite(), value.is_infinite());
+
+        let expected = json!({ "$numberDouble": repr });
+        assert_eq!(bson.clone().into_relaxed_extjson(), expected);
+        assert_eq!(bson.clone().into_canonical_extjson(), expected);
+        let parsed = Bson::try_from(expected).unwrap();
+        assert_eq!(parsed.is_nan(), value.is_nan());
+        assert_eq!(
+            parsed.as_f64().map(f64::is_sign_negative),
+            Some(value.is_sign_negative())
+        );
+
+        let doc = doc! { "x": bson };
+        let bytes = crate::to_vec(&doc).unwrap();
+        for decoded in [
+            Document::from_reader(bytes.as_slice()).unwrap(),
+            crate::from_slice::<Document>(&bytes).unwrap(),
+        ] {
+            let decoded = decoded.get_f64("x").unwrap();
+            assert_eq!(decoded.to_bits(), value.to_bits());
+        }
+    }
+
+    assert!(!Bson::Double(1.5).is_nan());
+    assert!(!Bson::Double(1.5).is_infinite());
+    assert!(!Bson::String("NaN".to_string()).is_nan());
+    assert!(!Bson::Int64(i64::MAX).is_infinite());
+}
+
+#[test]
+fn predicates() {
+    let _guard = LOCK.run_concurrently();
+    assert!(Bson::Null.is_null());
+    assert!(Bson::Boolean(false).is_boolean());
+    assert!(Bson::Int32(1).is_i32());
+    assert!(Bson::Int64(1).is_i64());
+    assert!(Bson::Double(1.0).is_f64());
+    assert!(Bson::String(String::new()).is_string());
+    assert!(Bson::Document(Document::new()).is_document());
+    assert!(Bson::Array(Vec::new()).is_array());
+    assert!(Bson::ObjectId(ObjectId::new()).is_object_id());
+    assert!(Bson::Binary(Binary {
+        subtype: BinarySubtype::Generic,
+        bytes: Vec::new(),
+    })
+    .is_binary());
+    assert!(Bson::DateTime(DateTime::now()).is_datetime());
+    assert!(Bson::RegularExpression(Regex::new("a", "i")).is_regex());
+    assert!(Bson::MinKey.is_min_key());
+    assert!(Bson::MaxKey.is_max_key());
+
+    for number in [Bson::Int32(1), Bson::Int64(1), Bson::Double(1.0)] {
+        assert!(number.is_number());
+    }
+    for not_number in [
+        Bson::String("1".to_string()),
+        Bson::Null,
+        Bson::Timestamp(Timestamp {
+            time: 1,
+            increment: 1,
+        }),
+    ] {
+        assert!(!not_number.is_number());
+    }
+
+    assert!(!Bson::Int32(1).is_i64());
+    assert!(!Bson::Int64(1).is_i32());
+    assert!(!Bson::Undefined.is_null());
+    assert!(!Bson::MaxKey.is_min_key());
+    assert!(!Bson::Document(Document::new()).is_array());
+    assert!(!Bson::Array(Vec::new()).is_document());
+}
+
+#[test]
+fn element_type_bytes() {
+    let _guard = LOCK.run_concurrently();
+    for tag in 0..=u8::MAX {
+        if let Some(element_type) = ElementType::from_u8(tag) {
+            assert_eq!(element_type.as_u8(), tag);
+        }
+    }
+
+    assert_eq!(ElementType::Double.as_u8(), 0x01);
+    assert_eq!(ElementType::Decimal128.as_u8(), 0x13);
+    assert_eq!(ElementType::MaxKey.as_u8(), 0x7F);
+    assert_eq!(ElementType::MinKey.as_u8(), 0xFF);
+    assert_eq!(ElementType::from_u8(0x00), None);
+    assert_eq!(ElementType::from_u8(0x14), None);
+
+    assert_eq!(
+        ElementType::from_u8(element_type::DOUBLE),
+        Some(ElementType::Double)
+    );
+    assert_eq!(element_type::STRING, 0x02);
+    assert_eq!(element_type::DECIMAL128, 0x13);
+    assert_eq!(element_type::MIN_KEY, ElementType::MinKey.as_u8());
+    assert_eq!(element_type::MAX_KEY, ElementType::MaxKey.as_u8());
+
+    assert_eq!(Bson::Int32(1).element_type().as_u8(), 0x10);
+    assert_eq!(
+        Bson::Document(Document::new()).element_type(),
+        ElementType::EmbeddedDocument
+    );
+}
+
+#[test]
+fn public_types_are_clone_and_debug() {
+    let _guard = LOCK.run_concurrently();
+    fn assert_clone_debug<T: Clone + std::fmt::Debug>() {}
+
+    assert_clone_debug::<Bson>();
+    assert_clone_debug::<Document>();
+    assert_clone_debug::<ObjectId>();
+    assert_clone_debug::<Regex>();
+    assert_clone_debug::<Timestamp>();
+    assert_clone_debug::<Decimal128>();
+    assert_clone_debug::<Binary>();
+    assert_clone_debug::<BinarySubtype>();
+    assert_clone_debug::<crate::de::Error>();
+    assert_clone_debug::<crate::ser::Error>();
+    assert_clone_debug::<crate::document::Iter>();
+    assert_clone_debug::<crate::raw::Iter>();
+    assert_clone_debug::<crate::raw::BsonCursor>();
+    assert_clone_debug::<crate::DeserializerOptions>();
+    assert_clone_debug::<crate::SerializerOptions>();
+}
+
+#[test]
+fn eq_value() {
+    let _guard = LOCK.run_concurrently();
+    let oid = ObjectId::new();
+    let doc = doc! {
+        "name": "Alice",
+        "count": 42,
+        "big": 42i64,
+        "ratio": 0.5,
+        "nan": f64::NAN,
+        "ok": true,
+        "_id": oid,
+    };
+
+    assert!(doc["name"].eq_value("Alice"));
+    assert!(doc["name"].eq_value("Alice".to_string()));
+    assert!(doc["count"].eq_value(42i32));
+    assert!(doc["big"].eq_value(42i64));
+    assert!(doc["ratio"].eq_value(0.5));
+    assert!(doc["nan"].eq_value(f64::NAN));
+    assert!(doc["ok"].eq_value(true));
+    assert!(doc["_id"].eq_value(oid));
+
+    // only the matching variant compares equal
+    assert!(!doc["count"].eq_value(42i64));
+    assert!(!doc["big"].eq_value(42i32));
+    assert!(!doc["ratio"].eq_value(1.0));
+    assert!(!doc["name"].eq_value("Bob"));
+    assert!(!Bson::Symbol("Alice".to_string()).eq_value("Alice"));
+    assert!(!Bson::Int32(1).eq_value(true));
+
+    // comparisons against values converted with `into` need no type annotations.
+    let name = "Alice";
+    assert!(doc["name"] == name.into());
+}
+
+#[test]
+fn type_name() {
+    let _guard = LOCK.run_concurrently();
+    assert_eq!(Bson::Double(1.0).type_name(), "double");
+    assert_eq!(Bson::String("x".into()).type_name(), "string");
+    assert_eq!(Bson::Document(Document::new()).type_name(), "document");
+    assert_eq!(Bson::Array(Vec::new()).type_name(), "array");
+    assert_eq!(Bson::ObjectId(ObjectId::new()).type_name(), "objectId");
+    assert_eq!(Bson::DateTime(DateTime::now()).type_name(), "date");
+    assert_eq!(Bson::Int32(1).type_name(), "int32");
+    assert_eq!(Bson::Int64(1).type_name(), "int64");
+    assert_eq!(Bson::MinKey.type_name(), "minKey");
+    assert_eq!(Bson::MaxKey.type_name(), "maxKey");
+}
+
+#[test]
+fn total_cmp() {
+    use std::cmp::Ordering;
+
+    let _guard = LOCK.run_concurrently();
+
+    // One representative per rank of the comparison order, from least to greatest.
+    let ascending = vec![
+        Bson::MinKey,
+        Bson::Undefined,
+        Bson::Null,
+        Bson::Int32(5),
+        Bson::String("a".to_string()),
+        Bson::Document(doc! {}),
+        Bson::Array(vec![]),
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: vec![],
+        }),
+        Bson::ObjectId(ObjectId::from_bytes([0; 12])),
+        Bson::Boolean(false),
+        Bson::DateTime(DateTime::from_millis(0)),
+        Bson::Timestamp(Timestamp {
+            time: 0,
+            increment: 0,
+        }),
+        Bson::RegularExpression(Regex::new("a", "")),
+        Bson::DbPointer(DbPointer {
+            namespace: "db.coll".to_string(),
+            id: ObjectId::from_bytes([0; 12]),
+        }),
+        Bson::JavaScriptCode("a".to_string()),
+        Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+            code: "a".to_string(),
+            scope: doc! {},
+        }),
+        Bson::MaxKey,
+    ];
+    for (i, a) in ascending.iter().enumerate() {
+        for (j, b) in ascending.iter().enumerate() {
+            assert_eq!(a.total_cmp(b), i.cmp(&j), "{:?} vs {:?}", a, b);
+        }
+    }
+
+    let five = [
+        Bson::Int32(5),
+        Bson::Int64(5),
+        Bson::Double(5.0),
+        Bson::Decimal128(Decimal128::parse_str("5.0").unwrap()),
+    ];
+    for a in &five {
+        for b in &five {
+            assert_eq!(a.total_cmp(b), Ordering::Equal, "{:?} vs {:?}", a, b);
+        }
+        assert_eq!(a.total_cmp(&Bson::Double(5.5)), Ordering::Less);
+        assert_eq!(a.total_cmp(&Bson::Int64(4)), Ordering::Greater);
+        assert_eq!(a.total_cmp(&Bson::Double(f64::NAN)), Ordering::Greater);
+        assert_eq!(
+            a.total_cmp(&Bson::Double(f64::NEG_INFINITY)),
+            Ordering::Greater
+        );
+    }
+    assert_eq!(
+        Bson::Double(f64::NAN).total_cmp(&Bson::Double(f64::NAN)),
+        Ordering::Equal
+    );
+
+    // Integers larger than 2^53 cannot be represented exactly as doubles.
+    assert_eq!(
+        Bson::Int64(i64::MAX).total_cmp(&Bson::Double(i64::MAX as f64)),
+        Ordering::Less
+    );
+    assert_eq!(
+        Bson::Int64((1 << 53) + 1).total_cmp(&Bson::Double((1u64 << 53) as f64)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Bson::Int64(i64::MIN).total_cmp(&Bson::Double(i64::MIN as f64)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        Bson::Double(-0.5).total_cmp(&Bson::Int32(0)),
+        Ordering::Less
+    );
+
+    assert_eq!(
+        Bson::Symbol("b".to_string()).total_cmp(&Bson::String("a".to_string())),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Bson::Symbol("a".to_string()).total_cmp(&Bson::String("a".to_string())),
+        Ordering::Equal
+    );
+
+    assert_eq!(
+        Bson::Array(vec![Bson::Int32(1)]).total_cmp(&Bson::Array(vec![Bson::Int32(1), Bson::Null])),
+        Ordering::Less
+    );
+    assert_eq!(
+        Bson::Array(vec![Bson::Int32(2)]).total_cmp(&Bson::Array(vec![Bson::Int32(1), Bson::Null])),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Bson::Document(doc! { "a": "x" }).total_cmp(&Bson::Document(doc! { "b": 1 })),
+        Ordering::Greater,
+        "value types are compared before keys"
+    );
+    assert_eq!(
+        Bson::Document(doc! { "a": 1 }).total_cmp(&Bson::Document(doc! { "b": 1 })),
+        Ordering::Less
+    );
+    assert_eq!(
+        Bson::Document(doc! { "a": 1 }).total_cmp(&Bson::Document(doc! { "a": 1.0 })),
+        Ordering::Equal
+    );
+
+    let binary = |subtype, bytes: &[u8]| {
+        Bson::Binary(Binary {
+            subtype,
+            bytes: bytes.to_vec(),
+        })
+    };
+    assert_eq!(
+        binary(BinarySubtype::UserDefined(0x80), b"a")
+            .total_cmp(&binary(BinarySubtype::Generic, b"aa")),
+        Ordering::Less
+    );
+    assert_eq!(
+        binary(BinarySubtype::Generic, b"b").total_cmp(&binary(BinarySubtype::Uuid, b"a")),
+        Ordering::Less
+    );
+
+    assert_eq!(
+        Bson::Boolean(true).total_cmp(&Bson::Boolean(false)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Bson::DateTime(DateTime::from_millis(-1))
+            .total_cmp(&Bson::DateTime(DateTime::from_millis(0))),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn hash() {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+    };
+
+    let _guard = LOCK.run_concurrently();
+
+    fn hash_of(value: &impl Hash) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    assert_eq!(Bson::Double(0.0), Bson::Double(-0.0));
+    assert_eq!(hash_of(&Bson::Double(0.0)), hash_of(&Bson::Double(-0.0)));
+    assert_eq!(Bson::Double(f64::NAN), Bson::Double(-f64::NAN));
+    assert_eq!(
+        hash_of(&Bson::Double(f64::NAN)),
+        hash_of(&Bson::Double(-f64::NAN))
+    );
+    assert_ne!(hash_of(&Bson::Int32(1)), hash_of(&Bson::Int64(1)));
+    assert_ne!(
+        hash_of(&Bson::String("a".to_string())),
+        hash_of(&Bson::Symbol("a".to_string()))
+    );
+
+    let forward = doc! { "a": 1, "b": { "c": [1, 2] } };
+    let backward = doc! { "b": { "c": [1, 2] }, "a": 1 };
+    assert_eq!(forward, backward);
+    assert_eq!(hash_of(&forward), hash_of(&backward));
+    assert_ne!(hash_of(&forward), hash_of(&doc! { "a": 1 }));
+    assert_ne!(
+        hash_of(&doc! { "a": 1, "b": 2 }),
+        hash_of(&doc! { "a": 2, "b": 1 })
+    );
+
+    let mut seen = HashSet::new();
+    assert!(seen.insert(Bson::Document(forward)));
+    assert!(!seen.insert(Bson::Document(backward)));
+    assert!(seen.insert(Bson::Array(vec![Bson::Null, Bson::MaxKey])));
+    assert!(!seen.insert(Bson::Array(vec![Bson::Null, Bson::MaxKey])));
+    assert!(seen.insert(Bson::Double(f64::NAN)));
+    assert!(seen.contains(&Bson::Double(f64::NAN)));
+    assert_eq!(seen.len(), 3);
+}
+
+#[test]
+fn array_helpers() {
+    let _guard = LOCK.run_concurrently();
+
+    let mut array = Bson::Array(vec![Bson::Int32(1), Bson::String("two".to_string())]);
+    assert_eq!(array.array_len(), Some(2));
+    assert_eq!(array.array_get(1), Some(&Bson::String("two".to_string())));
+    assert_eq!(array.array_get(2), None);
+
+    *array.array_get_mut(0).unwrap() = Bson::Int32(10);
+    assert!(array.array_push(3.5));
+    assert_eq!(
+        array.array_iter().unwrap().collect::<Vec<_>>(),
+        vec![
+            &Bson::Int32(10),
+            &Bson::String("two".to_string()),
+            &Bson::Double(3.5)
+        ]
+    );
+
+    let mut not_array = Bson::Document(Document::new());
+    assert_eq!(not_array.array_len(), None);
+    assert_eq!(not_array.array_get(0), None);
+    assert_eq!(not_array.array_get_mut(0), None);
+    assert!(!not_array.array_push(1));
+    assert!(not_array.array_iter().is_none());
+    assert_eq!(not_array, Bson::Document(Document::new()));
+}
+
+#[test]
+fn option_conversions() {
+    let _guard = LOCK.run_concurrently();
+    assert_eq!(Bson::from(None::<i32>), Bson::Null);
+    assert_eq!(Bson::from(Some(5)), Bson::Int32(5));
+    assert_eq!(Bson::from(Some(Bson::Null)), Bson::Null);
+
+    assert_eq!(Bson::Null.into_option(), None);
+    assert_eq!(
+        Bson::String("x".to_string()).into_option(),
+        Some(Bson::String("x".to_string()))
+    );
+    assert_eq!(Bson::Undefined.into_option(), Some(Bson::Undefined));
+
+    let none: Option<i32> = crate::from_bson(Bson::Null).unwrap();
+    assert_eq!(none, None);
+    let some: Option<i32> = crate::from_bson(Bson::Int32(5)).unwrap();
+    assert_eq!(some, Some(5));
+    assert_eq!(crate::to_bson(&None::<i32>).unwrap(), Bson::Null);
+}
+
 #[test]
 fn bson_default() {
     let _guard = LOCK.run_concurrently();
@@ -72,6 +592,45 @@ fn test_display_timestamp_type() {
     assert_eq!(format!("{}", Bson::from(x)), output);
 }
 
+#[test]
+fn timestamp_now() {
+    let _guard = LOCK.run_concurrently();
+    let before = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let ts = Timestamp::now();
+    let after = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    assert!((before..=after).contains(&(ts.time as u64)));
+    assert_eq!(ts.increment, 0);
+}
+
+#[test]
+fn timestamp_from_system_time() {
+    let _guard = LOCK.run_concurrently();
+    let st = SystemTime::UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+    assert_eq!(
+        Timestamp::from(st),
+        Timestamp {
+            time: 1_600_000_000,
+            increment: 0
+        }
+    );
+
+    let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+    assert_eq!(
+        Timestamp::from(before_epoch),
+        Timestamp {
+            time: 0,
+            increment: 0
+        }
+    );
+}
+
 #[test]
 fn test_display_regex_type() {
     let x = Regex {
@@ -107,6 +666,40 @@ fn test_display_binary_type() {
     assert_eq!(format!("{}", Bson::from(x)), output);
 }
 
+#[test]
+fn test_display_decimal128_type() {
+    let _guard = LOCK.run_concurrently();
+    for s in [
+        "0",
+        "-1.50",
+        "1.23E+40",
+        "-0.000001",
+        "1E-7",
+        "Infinity",
+        "-Infinity",
+        "NaN",
+    ] {
+        let d: Decimal128 = s.parse().unwrap();
+        assert_eq!(d.to_string(), s);
+        assert_eq!(Decimal128::parse_str(d.to_string()).unwrap(), d);
+    }
+
+    let d = Decimal128::parse_str("12.5").unwrap();
+    assert_eq!(format!("{:?}", d), "Decimal128(\"12.5\")");
+    assert_eq!(format!("{}", Bson::Decimal128(d)), "12.5");
+    assert_eq!(d, Decimal128::from_bytes(d.bytes()));
+    assert_ne!(d, Decimal128::parse_str("12.50").unwrap());
+
+    assert!(matches!(
+        "1.2.3".parse::<Decimal128>(),
+        Err(crate::decimal128::Error::InvalidDecimalString { .. })
+    ));
+    assert!(matches!(
+        "1234567890123456789012345678901234567".parse::<Decimal128>(),
+        Err(crate::decimal128::Error::InexactValue { .. })
+    ));
+}
+
 #[test]
 fn document_default() {
     let _guard = LOCK.run_concurrently();
@@ -115,6 +708,22 @@ fn document_default() {
     assert_eq!(doc1, Document::new());
 }
 
+#[test]
+fn derive_default_with_bson_fields() {
+    let _guard = LOCK.run_concurrently();
+    #[derive(Default)]
+    struct Wrapper {
+        value: Bson,
+        doc: Document,
+        values: Vec<Bson>,
+    }
+
+    let wrapper = Wrapper::default();
+    assert_eq!(wrapper.value, Bson::Null);
+    assert!(wrapper.doc.is_empty());
+    assert!(wrapper.values.is_empty());
+}
+
 #[test]
 fn from_impls() {
     let _guard = LOCK.run_concurrently();
@@ -180,7 +789,7 @@ fn from_impls() {
     // References
     assert_eq!(Bson::from(&24i32), Bson::Int32(24));
     assert_eq!(
-        Bson::try_from(&String::from("data")).unwrap(),
+        Bson::from(&String::from("data")),
         Bson::String(String::from("data"))
     );
     assert_eq!(Bson::from(&oid), Bson::ObjectId(oid));
@@ -211,6 +820,27 @@ fn from_impls() {
     assert_eq!(Bson::from(db_pointer), Bson::DbPointer(db_pointer.clone()));
 }
 
+#[test]
+fn cow_bson() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! { "a": { "b": [1, 2] } };
+
+    let borrowed: CowBson = Cow::Borrowed(doc.get("a").unwrap());
+    assert!(matches!(borrowed, Cow::Borrowed(_)));
+    assert_eq!(borrowed.as_document().map(Document::len), Some(1));
+    assert_eq!(Bson::from(borrowed), Bson::Document(doc! { "b": [1, 2] }));
+
+    let mut modified: CowBson = Cow::Borrowed(doc.get("a").unwrap());
+    modified
+        .to_mut()
+        .as_document_mut()
+        .unwrap()
+        .insert("c", true);
+    let modified: Bson = modified.into();
+    assert_eq!(modified, Bson::Document(doc! { "b": [1, 2], "c": true }));
+    assert_eq!(doc, doc! { "a": { "b": [1, 2] } });
+}
+
 #[test]
 fn timestamp_ordering() {
     let _guard = LOCK.run_concurrently();
@@ -238,7 +868,7 @@ fn from_external_datetime() {
     let _guard = LOCK.run_concurrently();
 
     fn assert_millisecond_precision(dt: DateTime) {
-        assert!(dt.to_time_0_3().microsecond() % 1000 == 0);
+        assert_eq!(dt.to_time_0_3().microsecond() % 1000, 0);
     }
     fn assert_subsec_millis(dt: DateTime, millis: u32) {
         assert_eq!(dt.to_time_0_3().millisecond() as u32, millis)
@@ -483,3 +1113,47 @@ fn debug_print() {
     assert_eq!(format!("{:?}", doc), normal_print);
     assert_eq!(format!("{:#?}", doc), pretty_print);
 }
+
+#[test]
+fn from_extended_document_oid() {
+    let _guard = LOCK.run_concurrently();
+    let extended = |oid: Bson| {
+        let mut doc = Document::new();
+        doc.insert("$oid", oid);
+        doc
+    };
+
+    assert_eq!(
+        Bson::from_extended_document(extended("507f1f77bcf86cd799439011".into())),
+        Bson::ObjectId(ObjectId::parse_str("507f1f77bcf86cd799439011").unwrap())
+    );
+
+    // values that aren't valid object IDs are left as documents: wrong lengths, invalid
+    // characters, and non-string values.
+    for oid in [
+        Bson::from("507f1f77bcf86cd79943901"),
+        Bson::from(""),
+        Bson::from("507f1f77bcf86cd79943901z"),
+        Bson::from(5),
+    ] {
+        let doc = extended(oid);
+        assert_eq!(
+            Bson::from_extended_document(doc.clone()),
+            Bson::Document(doc)
+        );
+    }
+
+    // to_bson and to_vec agree on such documents, at the top level and nested.
+    let map: std::collections::HashMap<_, _> = std::iter::once(("$oid", "xyz")).collect();
+    let nested = doc! { "_id": { "$oid": "not an object id" } };
+    assert_eq!(
+        crate::to_bson(&map).unwrap(),
+        Bson::Document(crate::decode_document_from_slice(&crate::to_vec(&map).unwrap()).unwrap())
+    );
+    assert_eq!(
+        crate::to_bson(&nested).unwrap(),
+        Bson::Document(
+            crate::decode_document_from_slice(&crate::to_vec(&nested).unwrap()).unwrap()
+        )
+    );
+}
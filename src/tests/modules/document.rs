@@ -1,13 +1,25 @@
+use std::collections::{BTreeMap, HashMap};
+
 use crate::{
-    doc,
-    document::ValueAccessError,
+    document::{
+        diff,
+        validate_document,
+        validate_raw_document,
+        PathError,
+        ValidationError,
+        ValueAccessError,
+    },
     oid::ObjectId,
     spec::BinarySubtype,
     tests::LOCK,
     Binary,
     Bson,
     Document,
+    JavaScriptCodeWithScope,
+    RawDocument,
+    RawDocumentBuf,
     Timestamp,
+    MAX_BSON_SIZE,
 };
 use time::OffsetDateTime;
 
@@ -245,3 +257,609 @@ fn extend() {
         },
     );
 }
+
+#[cfg(feature = "tokio")]
+#[test]
+fn watched() {
+    let _guard = LOCK.run_concurrently();
+    let (mut watched, mut rx) = crate::document::into_watched(doc! { "x": 1 });
+    assert_eq!(*rx.borrow_and_update(), doc! { "x": 1 });
+
+    assert_eq!(watched.insert("y", "two"), None);
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(*rx.borrow_and_update(), doc! { "x": 1, "y": "two" });
+
+    // removing a missing key is not a mutation.
+    assert_eq!(watched.remove("z"), None);
+    assert!(!rx.has_changed().unwrap());
+
+    assert_eq!(watched.remove("x"), Some(Bson::Int32(1)));
+    assert_eq!(*rx.borrow_and_update(), doc! { "y": "two" });
+
+    watched.clear();
+    assert!(rx.borrow_and_update().is_empty());
+
+    let old = watched.set(doc! { "a": true });
+    assert!(old.is_empty());
+    assert_eq!(*rx.borrow_and_update(), doc! { "a": true });
+    assert_eq!(watched.get_bool("a"), Ok(true));
+
+    let mut rx2 = watched.subscribe();
+    watched.insert("b", false);
+    assert_eq!(*rx2.borrow_and_update(), doc! { "a": true, "b": false });
+
+    assert_eq!(watched.into_inner(), doc! { "a": true, "b": false });
+    assert!(rx.has_changed().is_err());
+}
+
+#[test]
+fn index() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! {
+        "x": 1,
+        "y": { "z": "hello" },
+    };
+
+    assert_eq!(doc["x"], Bson::Int32(1));
+    assert_eq!(
+        doc["y"].as_document().unwrap()["z"],
+        Bson::String("hello".to_string())
+    );
+
+    doc["x"] = Bson::Boolean(true);
+    assert_eq!(doc, doc! { "x": true, "y": { "z": "hello" } });
+}
+
+#[test]
+#[should_panic(expected = "key \"missing\" not present in document")]
+fn index_missing_key() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! { "x": 1 };
+    let _ = &doc["missing"];
+}
+
+#[test]
+fn extend_str_keys() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = Document::with_capacity(2);
+    assert!(doc.is_empty());
+
+    doc.extend(vec![("a", Bson::Int32(1)), ("b", Bson::Null)]);
+    assert_eq!(doc, doc! { "a": 1, "b": Bson::Null });
+}
+
+#[test]
+fn collect_and_extend() {
+    let _guard = LOCK.run_concurrently();
+    let doc: Document = vec![("a", Bson::Int32(1)), ("b", Bson::Null)]
+        .into_iter()
+        .collect();
+    assert_eq!(doc.keys().collect::<Vec<_>>(), vec!["a", "b"]);
+
+    let mut merged: Document = doc
+        .into_iter()
+        .map(|(k, v)| (k.to_uppercase(), v))
+        .collect();
+    merged.extend(doc! { "C": true, "A": 2 });
+    assert_eq!(merged.keys().collect::<Vec<_>>(), vec!["A", "B", "C"]);
+    assert_eq!(merged, doc! { "A": 2, "B": Bson::Null, "C": true });
+}
+
+#[test]
+fn map_conversions() {
+    let _guard = LOCK.run_concurrently();
+    let mut btree = BTreeMap::new();
+    btree.insert("c".to_string(), Bson::Int32(3));
+    btree.insert("a".to_string(), Bson::Int32(1));
+    btree.insert("b".to_string(), Bson::Int32(2));
+
+    let doc = Document::from(btree);
+    let keys: Vec<_> = doc.keys().map(String::as_str).collect();
+    assert_eq!(keys, vec!["a", "b", "c"]);
+
+    let map: HashMap<String, Bson> = doc.clone().into();
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get("b"), Some(&Bson::Int32(2)));
+
+    let from_hash_map = Document::from(map);
+    assert_eq!(from_hash_map.len(), 3);
+    for (k, v) in &doc {
+        assert_eq!(from_hash_map.get(k), Some(v));
+    }
+}
+
+#[test]
+fn deep_merge() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! {
+        "a": { "x": 1, "nested": { "keep": true, "replace": 1 } },
+        "b": "replaced by a document",
+        "c": { "replaced": "by a scalar" },
+    };
+
+    doc.deep_merge(doc! {
+        "c": 5,
+        "a": { "nested": { "replace": 2, "new": 3 }, "y": 2 },
+        "b": { "sub": 1 },
+        "d": [1, 2],
+    });
+
+    assert_eq!(
+        doc,
+        doc! {
+            "a": { "x": 1, "nested": { "keep": true, "replace": 2, "new": 3 }, "y": 2 },
+            "b": { "sub": 1 },
+            "c": 5,
+            "d": [1, 2],
+        }
+    );
+}
+
+#[test]
+fn flatten_unflatten() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! {
+        "a": { "b": 1, "c": { "d": "e" } },
+        "arr": [{ "x": 1 }],
+        "empty": {},
+        "top": true,
+    };
+
+    let flat = doc.flatten().unwrap();
+    assert_eq!(
+        flat,
+        doc! {
+            "a.b": 1,
+            "a.c.d": "e",
+            "arr": [{ "x": 1 }],
+            "empty": {},
+            "top": true,
+        }
+    );
+    assert_eq!(Document::unflatten(&flat).unwrap(), doc);
+
+    assert_eq!(
+        Document::unflatten(&doc! { "a": 1, "a.b": 2 }),
+        Err(PathError::Conflict { path: "a".into() })
+    );
+    assert_eq!(
+        Document::unflatten(&doc! { "a.b.c": 1, "a.b": 2 }),
+        Err(PathError::Conflict { path: "a.b".into() })
+    );
+    assert_eq!(
+        Document::unflatten(&doc! { "a..b": 1 }),
+        Err(PathError::EmptySegment {
+            path: "a..b".into()
+        })
+    );
+
+    assert_eq!(
+        doc! { "a": { "b": 1 }, "a.b": 2 }.flatten(),
+        Err(PathError::Conflict { path: "a.b".into() })
+    );
+    assert_eq!(
+        doc! { "a.b": 2, "a": { "b": 1 } }.flatten(),
+        Err(PathError::Conflict { path: "a.b".into() })
+    );
+}
+
+#[test]
+fn path_mutation() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! { "a": { "b": 1 }, "n": 5 };
+
+    doc.set("a.b", 2).unwrap();
+    doc.set("a.c.d", "new").unwrap();
+    doc.set("top", true).unwrap();
+    assert_eq!(
+        doc,
+        doc! { "a": { "b": 2, "c": { "d": "new" } }, "n": 5, "top": true }
+    );
+    assert_eq!(
+        doc.set("n.x", 1),
+        Err(PathError::Conflict { path: "n".into() })
+    );
+    assert_eq!(
+        doc.set("a..b", 1),
+        Err(PathError::EmptySegment {
+            path: "a..b".into()
+        })
+    );
+
+    doc.array_push("a.list", 1).unwrap();
+    doc.array_push("a.list", "two").unwrap();
+    doc.array_push("x.y", Bson::Null).unwrap();
+    assert_eq!(
+        doc.get("x"),
+        Some(&Bson::Document(doc! { "y": [Bson::Null] }))
+    );
+    assert_eq!(
+        doc.array_push("a.b", 3),
+        Err(PathError::Conflict { path: "a.b".into() })
+    );
+
+    assert!(doc.unset("a.c.d"));
+    assert!(doc.unset("top"));
+    assert!(!doc.unset("top"));
+    assert!(!doc.unset("n.x"));
+    assert!(!doc.unset("missing.path"));
+    assert_eq!(
+        doc,
+        doc! { "a": { "b": 2, "c": {}, "list": [1, "two"] }, "n": 5, "x": { "y": [Bson::Null] } }
+    );
+}
+
+#[test]
+fn project() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! {
+        "_id": 1,
+        "name": "Alice",
+        "address": { "city": "Paris", "zip": "75001", "geo": { "lat": 1.5, "lng": 2.5 } },
+        "tags": [{ "a": 1 }],
+    };
+
+    assert_eq!(
+        doc.project(&["name", "_id", "missing"]),
+        doc! { "_id": 1, "name": "Alice" }
+    );
+    assert_eq!(
+        doc.project(&["address.geo.lat", "address.city", "name.first"]),
+        doc! { "address": { "city": "Paris", "geo": { "lat": 1.5 } } }
+    );
+    assert_eq!(
+        doc.project(&["address", "address.city"]),
+        doc! { "address": doc.get("address").unwrap().clone() }
+    );
+    assert_eq!(doc.project(&["tags.a"]), doc! {});
+    assert_eq!(doc.project(&[]), doc! {});
+
+    assert_eq!(
+        doc.project_exclude(&["address.geo", "address.zip", "tags", "missing.path"]),
+        doc! { "_id": 1, "name": "Alice", "address": { "city": "Paris" } }
+    );
+    assert_eq!(doc.project_exclude(&[]), doc);
+}
+
+#[test]
+fn sort_keys() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! { "c": 3, "a": 1, "B": 0, "b": { "z": 1, "y": 2 } };
+    assert!(!doc.is_sorted());
+
+    let sorted = doc.clone().into_sorted();
+    doc.sort_keys();
+    assert_eq!(doc, sorted);
+    assert!(doc.is_sorted());
+
+    let keys: Vec<_> = doc.keys().map(String::as_str).collect();
+    assert_eq!(keys, vec!["B", "a", "b", "c"]);
+
+    // sorting is not recursive.
+    assert!(!doc.get_document("b").unwrap().is_sorted());
+    assert!(Document::new().is_sorted());
+}
+
+#[test]
+fn from_relaxed_extjson() {
+    let _guard = LOCK.run_concurrently();
+    let oid = ObjectId::parse_str("507f1f77bcf86cd799439011").unwrap();
+    let doc = doc! {
+        "_id": oid,
+        "n": 5,
+        "big": 1_i64 << 40,
+        "f": 1.5,
+        "bin": Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2, 3] },
+        "nested": { "arr": [1, "two"] },
+    };
+
+    let json = doc.to_json_string();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(Document::from_relaxed_extjson(value).unwrap(), doc);
+    assert_eq!(
+        Document::from_relaxed_extjson(doc.to_canonical_extjson()).unwrap(),
+        doc
+    );
+
+    assert!(Document::from_relaxed_extjson(serde_json::json!([1])).is_err());
+    assert!(Document::from_relaxed_extjson(serde_json::json!({ "$oid": oid.to_hex() })).is_err());
+    assert!(Document::from_relaxed_extjson(
+        serde_json::json!({ "d": { "$numberDecimal": "1.2.3" } })
+    )
+    .is_err());
+}
+
+#[test]
+fn byte_len() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! {
+        "double": 1.5,
+        "string": "hello",
+        "array": (0..12).map(Bson::Int32).collect::<Vec<_>>(),
+        "document": { "a": { "b": [] } },
+        "binary": Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2, 3] },
+        "old_binary": Binary { subtype: BinarySubtype::BinaryOld, bytes: vec![1, 2, 3] },
+        "undefined": Bson::Undefined,
+        "oid": ObjectId::new(),
+        "bool": true,
+        "datetime": crate::DateTime::now(),
+        "null": Bson::Null,
+        "regex": crate::Regex { pattern: "ab+c".into(), options: "i".into() },
+        "code": Bson::JavaScriptCode("x = 1".into()),
+        "symbol": Bson::Symbol("sym".into()),
+        "code_w_scope": crate::JavaScriptCodeWithScope { code: "x".into(), scope: doc! { "x": 1 } },
+        "i32": 1,
+        "timestamp": Timestamp { time: 1, increment: 2 },
+        "i64": 1_i64,
+        "decimal": crate::Decimal128::from_bytes([0; 16]),
+        "min": Bson::MinKey,
+        "max": Bson::MaxKey,
+    };
+
+    let mut bytes = Vec::new();
+    doc.to_writer(&mut bytes).unwrap();
+    assert_eq!(doc.byte_len(), bytes.len());
+    assert_eq!(Document::new().byte_len(), 5);
+    assert!(doc.is_within_size_limit());
+
+    let large = doc! {
+        "blob": Binary { subtype: BinarySubtype::Generic, bytes: vec![0; crate::MAX_BSON_SIZE] },
+    };
+    assert!(!large.is_within_size_limit());
+    assert!(large.byte_len() <= crate::MAX_ENCODED_DOCUMENT_SIZE);
+    large.to_writer(std::io::sink()).unwrap();
+}
+
+#[test]
+fn get_or_insert() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! { "a": 1 };
+
+    assert_eq!(doc.get_or_insert("a", 5), &mut Bson::Int32(1));
+    *doc.get_or_insert("b", 2) = Bson::String("two".into());
+    doc.get_or_insert(String::from("c"), doc! {})
+        .as_document_mut()
+        .unwrap()
+        .insert("d", true);
+
+    assert_eq!(doc, doc! { "a": 1, "b": "two", "c": { "d": true } });
+    assert_eq!(doc.keys().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn rename_key() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! { "a": 1, "b": 2, "c": 3 };
+
+    assert!(doc.rename_key("b", "x"));
+    assert_eq!(doc.keys().collect::<Vec<_>>(), vec!["a", "x", "c"]);
+    assert_eq!(doc.get_i32("x"), Ok(2));
+
+    assert!(doc.rename_key("c", String::from("z")));
+    assert_eq!(doc.keys().collect::<Vec<_>>(), vec!["a", "x", "z"]);
+
+    assert!(doc.rename_key("a", "a"));
+    assert!(!doc.rename_key("missing", "y"));
+    assert!(!doc.rename_key("a", "x"));
+    assert_eq!(doc.keys().collect::<Vec<_>>(), vec!["a", "x", "z"]);
+    assert_eq!(doc, doc! { "a": 1, "x": 2, "z": 3 });
+}
+
+#[test]
+fn pop_first_and_last() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! { "a": 1, "b": 2, "c": 3 };
+
+    assert_eq!(doc.pop_first(), Some(("a".to_string(), Bson::Int32(1))));
+    assert_eq!(doc.pop_last(), Some(("c".to_string(), Bson::Int32(3))));
+    assert_eq!(doc, doc! { "b": 2 });
+
+    doc.insert("d", 4);
+    assert_eq!(doc.pop_first(), Some(("b".to_string(), Bson::Int32(2))));
+    assert_eq!(doc.pop_first(), Some(("d".to_string(), Bson::Int32(4))));
+    assert_eq!(doc.pop_first(), None);
+    assert_eq!(doc.pop_last(), None);
+}
+
+#[test]
+fn code_with_scope_byte_len() {
+    let _guard = LOCK.run_concurrently();
+    let scope: Document = (0..100)
+        .map(|i| (format!("f{}", i), Bson::Int32(i)))
+        .collect();
+    let doc = doc! {
+        "cws": JavaScriptCodeWithScope { code: "return f0 + f99;".into(), scope },
+    };
+
+    // each scope element is a type byte, its key and null terminator, and four value bytes
+    let scope_len = 4 + (10 * (1 + 2 + 1 + 4)) + (90 * (1 + 3 + 1 + 4)) + 1;
+    // total length prefix, length-prefixed null-terminated code string, then the scope
+    let cws_len = 4 + (4 + "return f0 + f99;".len() + 1) + scope_len;
+    let expected = 4 + (1 + "cws".len() + 1 + cws_len) + 1;
+    assert_eq!(expected, 930);
+
+    assert_eq!(doc.byte_len(), expected);
+    assert_eq!(crate::to_vec(&doc).unwrap().len(), expected);
+    assert_eq!(crate::encode_document_to_vec(&doc).unwrap().len(), expected);
+}
+
+#[test]
+fn validate() {
+    let _guard = LOCK.run_concurrently();
+
+    let valid = doc! {
+        "name": "ferris",
+        "nested": { "array": [1, { "x": true }, [2, 3]] },
+        "code": JavaScriptCodeWithScope { code: "x".to_string(), scope: doc! { "$x": 1 } },
+    };
+    assert_eq!(validate_document(&valid), Ok(()));
+    assert_eq!(
+        validate_raw_document(&RawDocumentBuf::from_document(&valid).unwrap()),
+        Ok(())
+    );
+
+    let invalid = doc! {
+        "a.b": 1,
+        "$c": { "d.e": [{ "$f": 1 }] },
+        "g\0h": 1,
+    };
+    let expected = vec![
+        ValidationError::KeyContainsDot("a.b".to_string()),
+        ValidationError::KeyStartsWithDollar("$c".to_string()),
+        ValidationError::KeyContainsDot("$c.d.e".to_string()),
+        ValidationError::KeyStartsWithDollar("$c.d.e.0.$f".to_string()),
+        ValidationError::EmbeddedNullInKey("g\0h".to_string()),
+    ];
+    assert_eq!(validate_document(&invalid), Err(expected));
+
+    let large =
+        doc! { "bytes": Binary { subtype: BinarySubtype::Generic, bytes: vec![0; MAX_BSON_SIZE] } };
+    assert_eq!(
+        validate_document(&large),
+        Err(vec![ValidationError::DocumentTooLarge {
+            actual: large.byte_len()
+        }])
+    );
+
+    // { "a": 1, "a": "\xff", "b": { "c": 1, "c": 2 } }
+    let mut raw = Vec::new();
+    raw.extend_from_slice(b"\x10a\x00\x01\x00\x00\x00");
+    raw.extend_from_slice(b"\x02a\x00\x02\x00\x00\x00\xff\x00");
+    raw.extend_from_slice(b"\x03b\x00\x13\x00\x00\x00");
+    raw.extend_from_slice(b"\x10c\x00\x01\x00\x00\x00\x10c\x00\x02\x00\x00\x00\x00");
+    raw.push(0);
+    let mut bytes = ((raw.len() + 4) as i32).to_le_bytes().to_vec();
+    bytes.extend(raw);
+    let raw = RawDocument::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        validate_raw_document(raw),
+        Err(vec![
+            ValidationError::DuplicateKey("a".to_string()),
+            ValidationError::InvalidUtf8InString("a".to_string()),
+            ValidationError::DuplicateKey("b.c".to_string()),
+        ])
+    );
+
+    let mut bytes = rawdoc! { "arr": ["~", { "$x": 1 }] }.into_bytes();
+    let i = bytes.iter().position(|b| *b == b'~').unwrap();
+    bytes[i] = 0xff;
+    let raw = RawDocument::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        validate_raw_document(raw),
+        Err(vec![
+            ValidationError::InvalidUtf8InString("arr.0".to_string()),
+            ValidationError::KeyStartsWithDollar("arr.1.$x".to_string()),
+        ])
+    );
+
+    let raw = rawdoc! { "a": 1, "b": { "$c": 1 }, "d": ["x", "y"] };
+    assert_eq!(
+        validate_raw_document(&raw),
+        Err(vec![ValidationError::KeyStartsWithDollar(
+            "b.$c".to_string()
+        )])
+    );
+
+    let mut bytes =
+        b"\x13\x00\x00\x00\x10a\x00\x01\x00\x00\x00\x10a\x00\x02\x00\x00\x00\x00".to_vec();
+    assert_eq!(bytes.len(), 0x13);
+    let raw = RawDocument::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        validate_raw_document(raw),
+        Err(vec![ValidationError::DuplicateKey("a".to_string())])
+    );
+
+    // Replace the second element's type with an unknown type.
+    bytes[11] = 0x42;
+    let raw = RawDocument::from_bytes(&bytes).unwrap();
+    assert!(matches!(
+        validate_raw_document(raw).unwrap_err().as_slice(),
+        [ValidationError::Malformed(_)]
+    ));
+}
+
+#[test]
+fn document_diff() {
+    let _guard = LOCK.run_concurrently();
+
+    let old = doc! {
+        "same": 1,
+        "changed": 1,
+        "retyped": 1,
+        "gone": "x",
+        "nested": { "same": true, "changed": "a", "gone": 1, "deeper": { "x": 1 } },
+        "array": [1, { "a": 1 }],
+        "replaced": { "a": 1 },
+    };
+    let new = doc! {
+        "same": 1,
+        "changed": 2,
+        "retyped": 1i64,
+        "nested": { "same": true, "changed": "b", "deeper": { "x": 1, "y": 2 }, "new": null },
+        "array": [1, { "a": 2 }],
+        "replaced": "a",
+        "added": { "a": 1 },
+    };
+
+    let result = diff(&old, &new);
+    assert_eq!(
+        result.added,
+        doc! { "nested.deeper.y": 2, "nested.new": null, "added": { "a": 1 } }
+    );
+    assert_eq!(
+        result.removed,
+        vec!["nested.gone".to_string(), "gone".to_string()]
+    );
+    assert_eq!(
+        result.modified,
+        doc! {
+            "changed": 2,
+            "retyped": 1i64,
+            "nested.changed": "b",
+            "array": [1, { "a": 2 }],
+            "replaced": "a",
+        }
+    );
+    assert!(!result.is_empty());
+
+    assert!(diff(&old, &old.clone()).is_empty());
+    assert!(diff(&doc! { "a": 1, "b": 2 }, &doc! { "b": 2, "a": 1 }).is_empty());
+    assert_eq!(
+        diff(&new, &old).added,
+        doc! { "gone": "x", "nested.gone": 1 }
+    );
+}
+
+#[test]
+fn debug_format() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! { "z": 1, "a": "two", "m": { "x": true } };
+    assert_eq!(
+        format!("{:?}", doc),
+        r#"Document({"z": Int32(1), "a": String("two"), "m": Document({"x": Boolean(true)})})"#
+    );
+    assert_eq!(format!("{:?}", Document::new()), "Document({})");
+}
+
+#[test]
+fn equality_ignores_key_order() {
+    let _guard = LOCK.run_concurrently();
+    let a = doc! {
+        "x": 1,
+        "nested": { "a": 1, "b": { "c": 2, "d": 3 } },
+        "array": [{ "p": 1, "q": 2 }],
+    };
+    let b = doc! {
+        "array": [{ "q": 2, "p": 1 }],
+        "nested": { "b": { "d": 3, "c": 2 }, "a": 1 },
+        "x": 1,
+    };
+    assert_eq!(a, b);
+    assert!(!a.iter().eq(b.iter()));
+    assert!(a.iter().eq(a.clone().iter()));
+
+    // array element order still matters, as do values and types
+    assert_ne!(doc! { "array": [1, 2] }, doc! { "array": [2, 1] });
+    assert_ne!(doc! { "x": 1 }, doc! { "x": 1i64 });
+    assert_ne!(doc! { "x": 1 }, doc! { "x": 1, "y": 2 });
+}
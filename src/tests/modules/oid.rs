@@ -58,3 +58,56 @@ fn fromstr_oid() {
     let actual_s = hex::encode(oid_res.unwrap().bytes());
     assert_eq!(s, &actual_s, "parsed and expected oids differ");
 }
+
+#[test]
+fn byte_access() {
+    let _guard = LOCK.run_concurrently();
+    let bytes = *b"\x54\x1b\x1a\x00\xe8\xa2\x3a\xfa\x83\x2b\x21\x8e";
+    let oid = ObjectId::from_bytes(bytes);
+    assert_eq!(oid.as_bytes(), &bytes);
+    assert_eq!(oid.as_ref(), &bytes[..]);
+    assert_eq!(ObjectId::from_bytes(*oid.as_bytes()), oid);
+}
+
+#[test]
+fn hash_set() {
+    let _guard = LOCK.run_concurrently();
+    let oids: Vec<ObjectId> = (0..1000).map(|_| ObjectId::new()).collect();
+    let set: std::collections::HashSet<ObjectId> = oids.iter().copied().collect();
+    assert_eq!(set.len(), 1000);
+    assert!(oids.iter().all(|oid| set.contains(oid)));
+    assert!(!set.contains(&ObjectId::from_bytes([0; 12])));
+}
+
+#[test]
+fn from_datetime() {
+    let _guard = LOCK.run_concurrently();
+    let oid = ObjectId::new();
+    let seconds = (oid.timestamp().timestamp_millis() / 1000) as u32;
+
+    let lower = ObjectId::from_datetime(seconds);
+    assert_eq!(lower.timestamp(), oid.timestamp());
+    assert_eq!(&lower.bytes()[4..], &[0; 8]);
+    assert!(lower <= oid);
+    assert!(ObjectId::from_datetime(seconds + 1) > oid);
+
+    // other processes generate ObjectIds with different process ids and counters
+    let mut smallest = lower.bytes();
+    smallest[11] = 1;
+    assert!(lower < ObjectId::from_bytes(smallest));
+    assert!(lower <= ObjectId::from_bytes(lower.bytes()));
+}
+
+#[test]
+fn unique_across_threads() {
+    let _guard = LOCK.run_concurrently();
+    let handles: Vec<_> = (0..100)
+        .map(|_| std::thread::spawn(|| (0..1000).map(|_| ObjectId::new()).collect::<Vec<_>>()))
+        .collect();
+
+    let mut oids = std::collections::HashSet::new();
+    for handle in handles {
+        oids.extend(handle.join().unwrap());
+    }
+    assert_eq!(oids.len(), 100_000);
+}
@@ -1,4 +1,4 @@
-use std::{collections::BTreeMap, u16, u32, u64, u8};
+use std::collections::BTreeMap;
 
 use assert_matches::assert_matches;
 
@@ -116,6 +116,29 @@ fn uint64_u2i() {
     );
 }
 
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn usize_out_of_range() {
+    let _guard = LOCK.run_concurrently();
+    #[derive(serde::Serialize)]
+    struct Foo {
+        len: usize,
+    }
+
+    let fits = i64::MAX as usize;
+    assert_eq!(to_bson(&fits).unwrap(), Bson::Int64(i64::MAX));
+    assert!(to_vec(&Foo { len: fits }).is_ok());
+
+    assert_matches!(
+        to_bson(&usize::MAX),
+        Err(ser::Error::UnsignedIntegerExceededRange(v)) if v == usize::MAX as u64
+    );
+    assert_matches!(
+        to_vec(&Foo { len: usize::MAX }),
+        Err(ser::Error::UnsignedIntegerExceededRange(v)) if v == usize::MAX as u64
+    );
+}
+
 #[test]
 fn int64() {
     let _guard = LOCK.run_concurrently();
@@ -147,18 +170,42 @@ fn cstring_null_bytes_error() {
     let _guard = LOCK.run_concurrently();
 
     let doc = doc! { "\0": "a" };
-    verify_doc(doc);
+    verify_key(doc);
 
     let doc = doc! { "a": { "\0": "b" } };
-    verify_doc(doc);
+    verify_key(doc);
 
     let regex = doc! { "regex": Regex { pattern: "\0".into(), options: "a".into() } };
-    verify_doc(regex);
+    verify_cstring(regex);
 
     let regex = doc! { "regex": Regex { pattern: "a".into(), options: "\0".into() } };
-    verify_doc(regex);
+    verify_cstring(regex);
 
-    fn verify_doc(doc: Document) {
+    #[derive(serde::Serialize)]
+    enum Foo {
+        #[serde(rename = "a\0b")]
+        Struct { x: i32 },
+    }
+    let error = to_vec(&doc! { "foo": to_bson(&Foo::Struct { x: 1 }).unwrap() }).unwrap_err();
+    assert!(matches!(error, ser::Error::InvalidKey { ref key, .. } if key == "a\0b"));
+    assert_eq!(
+        to_vec(&Foo::Struct { x: 1 }).unwrap_err().to_string(),
+        "invalid key \"a\\0b\": keys cannot contain null bytes"
+    );
+
+    fn verify_key(doc: Document) {
+        let mut vec = Vec::new();
+        assert!(matches!(
+            doc.to_writer(&mut vec).unwrap_err(),
+            ser::Error::InvalidKey { ref key, .. } if key == "\0"
+        ));
+        assert!(matches!(
+            to_vec(&doc).unwrap_err(),
+            ser::Error::InvalidKey { ref key, .. } if key == "\0"
+        ));
+    }
+
+    fn verify_cstring(doc: Document) {
         let mut vec = Vec::new();
         assert!(matches!(
             doc.to_writer(&mut vec).unwrap_err(),
@@ -170,3 +217,23 @@ fn cstring_null_bytes_error() {
         ));
     }
 }
+
+#[test]
+fn string_null_bytes_round_trip() {
+    let _guard = LOCK.run_concurrently();
+
+    // unlike keys, string values are length-prefixed, so embedded null bytes are valid.
+    let doc = doc! {
+        "string": "a\0b",
+        "code": Bson::JavaScriptCode("\0".into()),
+        "symbol": Bson::Symbol("\0\0".into()),
+    };
+
+    let mut vec = Vec::new();
+    doc.to_writer(&mut vec).unwrap();
+    assert_eq!(Document::from_reader(vec.as_slice()).unwrap(), doc);
+    assert_eq!(
+        crate::from_slice::<Document>(&to_vec(&doc).unwrap()).unwrap(),
+        doc
+    );
+}
@@ -18,6 +18,8 @@ use crate::{
     Decimal128,
     Document,
     JavaScriptCodeWithScope,
+    RawDocument,
+    RawDocumentBuf,
     Regex,
     Timestamp,
 };
@@ -77,6 +79,82 @@ fn test_encode_decode_utf8_string_invalid() {
     assert_eq!(decoded, expected);
 }
 
+#[test]
+fn test_decode_invalid_utf8_is_an_error() {
+    let _guard = LOCK.run_concurrently();
+    #[derive(Debug, Deserialize)]
+    struct Borrowed<'a> {
+        #[allow(dead_code)]
+        key: &'a str,
+    }
+
+    // replace the two-byte value "ab" with a sequence that isn't valid UTF-8
+    let mut value = crate::to_vec(&doc! { "key": "ab" }).unwrap();
+    let at = value.len() - 4;
+    value[at..at + 2].copy_from_slice(b"\x80\xae");
+
+    let mut key = crate::to_vec(&doc! { "ab": 1 }).unwrap();
+    key[5..7].copy_from_slice(b"\x80\xae");
+
+    for bytes in [&value, &key] {
+        assert!(matches!(
+            Document::from_reader(bytes.as_slice()),
+            Err(crate::de::Error::InvalidUtf8String(_))
+        ));
+        assert!(matches!(
+            crate::from_slice::<Document>(bytes),
+            Err(crate::de::Error::InvalidUtf8String(_))
+        ));
+    }
+
+    assert_eq!(
+        Document::from_reader_utf8_lossy(value.as_slice()).unwrap(),
+        doc! { "key": "\u{FFFD}\u{FFFD}" }
+    );
+
+    assert!(matches!(
+        crate::from_slice::<Borrowed>(&value),
+        Err(crate::de::Error::InvalidUtf8String(_))
+    ));
+    assert!(matches!(
+        RawDocument::from_bytes(&value).unwrap().get("key"),
+        Err(e) if matches!(e.kind, crate::raw::ErrorKind::Utf8EncodingError(_))
+    ));
+
+    // invalid strings in nested values are reported the same way.
+    let mut nested = crate::to_vec(&doc! { "users": [{ "email": "ab" }] }).unwrap();
+    let at = nested.windows(3).position(|w| w == b"ab\0").unwrap();
+    nested[at..at + 2].copy_from_slice(b"\x80\xae");
+    for error in [
+        Document::from_reader(nested.as_slice()).unwrap_err(),
+        crate::from_slice::<Document>(&nested).unwrap_err(),
+    ] {
+        assert!(matches!(error, crate::de::Error::InvalidUtf8String(_)));
+        assert!(error.path().is_empty());
+        assert!(error.to_string().starts_with("invalid utf-8"), "{}", error);
+    }
+}
+
+#[test]
+fn test_from_reader_consecutive_documents() {
+    let _guard = LOCK.run_concurrently();
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let mut bytes = crate::to_vec(&doc! { "x": 1, "y": 2 }).unwrap();
+    bytes.extend(crate::to_vec(&doc! { "y": 4, "x": 3, "z": "ignored" }).unwrap());
+
+    let mut reader = Cursor::new(bytes);
+    let first: Point = crate::from_reader(&mut reader).unwrap();
+    let second: Point = crate::from_reader(&mut reader).unwrap();
+    assert_eq!(first, Point { x: 1, y: 2 });
+    assert_eq!(second, Point { x: 3, y: 4 });
+    assert!(crate::from_reader::<_, Point>(&mut reader).is_err());
+}
+
 #[test]
 fn test_serialize_deserialize_array() {
     let _guard = LOCK.run_concurrently();
@@ -268,6 +346,129 @@ fn test_serialize_deserialize_timestamp() {
     assert_eq!(deserialized, doc);
 }
 
+#[test]
+fn test_encode_decode_little_endian() {
+    let _guard = LOCK.run_concurrently();
+    // every field value uses distinct bytes so that any byte swapping shows up in the output
+    let doc = doc! {
+        "i": 0x0102_0304,
+        "l": 0x0102_0304_0506_0708i64,
+        "t": Timestamp { time: 0x0102_0304, increment: 0x0506_0708 },
+    };
+    let dst = vec![
+        34, 0, 0, 0, // document length
+        0x10, b'i', 0, 0x04, 0x03, 0x02, 0x01, // int32
+        0x12, b'l', 0, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // int64
+        0x11, b't', 0, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // increment, then time
+        0,
+    ];
+
+    let mut buf = Vec::new();
+    doc.to_writer(&mut buf).unwrap();
+    assert_eq!(buf, dst);
+    assert_eq!(crate::to_vec(&doc).unwrap(), dst);
+    assert_eq!(
+        RawDocumentBuf::from_document(&doc).unwrap().as_bytes(),
+        &dst[..]
+    );
+
+    assert_eq!(Document::from_reader(&mut Cursor::new(&dst)).unwrap(), doc);
+    assert_eq!(crate::from_slice::<Document>(&dst).unwrap(), doc);
+    let raw = RawDocument::from_bytes(&dst).unwrap();
+    assert_eq!(
+        raw.get_timestamp("t").unwrap(),
+        Timestamp {
+            time: 0x0102_0304,
+            increment: 0x0506_0708
+        }
+    );
+}
+
+#[test]
+fn test_round_trip_every_variant() {
+    let _guard = LOCK.run_concurrently();
+    let values = vec![
+        Bson::Double(0.0),
+        Bson::Double(-0.0),
+        Bson::Double(f64::NAN),
+        Bson::Double(f64::INFINITY),
+        Bson::Double(f64::MIN_POSITIVE),
+        Bson::String(String::new()),
+        Bson::String("héllo wörld ✓ 🎉".to_string()),
+        Bson::String("embedded\0null".to_string()),
+        Bson::Array(Vec::new()),
+        Bson::Array(vec![Bson::Null, Bson::Array(Vec::new()), Bson::Int32(1)]),
+        Bson::Document(Document::new()),
+        Bson::Document(doc! { "nested": { "deeper": {} } }),
+        Bson::Boolean(true),
+        Bson::Boolean(false),
+        Bson::Null,
+        Bson::RegularExpression(Regex::new("^a.*b$", "im")),
+        Bson::RegularExpression(Regex::new("", "")),
+        Bson::JavaScriptCode(String::new()),
+        Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+            code: "x".to_string(),
+            scope: doc! { "x": 1 },
+        }),
+        Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+            code: String::new(),
+            scope: Document::new(),
+        }),
+        Bson::Int32(i32::MAX),
+        Bson::Int32(i32::MIN),
+        Bson::Int32(0),
+        Bson::Int64(i64::MAX),
+        Bson::Int64(i64::MIN),
+        Bson::Timestamp(Timestamp {
+            time: u32::MAX,
+            increment: u32::MAX,
+        }),
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: Vec::new(),
+        }),
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::UserDefined(0x80),
+            bytes: vec![0, 1, 2, 255],
+        }),
+        Bson::ObjectId(ObjectId::from_bytes([0; 12])),
+        Bson::ObjectId(ObjectId::new()),
+        Bson::DateTime(crate::DateTime::MIN),
+        Bson::DateTime(crate::DateTime::MAX),
+        Bson::DateTime(crate::DateTime::from_millis(0)),
+        Bson::Symbol("sym".to_string()),
+        Bson::Decimal128(Decimal128::from_bytes([0; 16])),
+        Bson::Undefined,
+        Bson::MaxKey,
+        Bson::MinKey,
+        Bson::DbPointer(crate::DbPointer {
+            namespace: "db.coll".to_string(),
+            id: ObjectId::new(),
+        }),
+    ];
+
+    for value in values {
+        let doc = doc! { "v": value.clone() };
+        let mut bytes = Vec::new();
+        doc.to_writer(&mut bytes).unwrap();
+
+        let decoded = [
+            Document::from_reader(&mut bytes.as_slice()).unwrap(),
+            crate::from_slice::<Document>(&bytes).unwrap(),
+            Document::try_from(RawDocument::from_bytes(&bytes).unwrap()).unwrap(),
+        ];
+        for decoded in decoded.iter() {
+            let decoded = decoded.get("v").unwrap();
+            assert_eq!(decoded, &value, "{:?}", value);
+            // Bson's PartialEq considers 0.0 and -0.0 equal, so compare doubles bitwise
+            if let (Bson::Double(a), Bson::Double(b)) = (decoded, &value) {
+                assert_eq!(a.to_bits(), b.to_bits());
+            }
+        }
+        assert_eq!(crate::to_vec(&doc).unwrap(), bytes, "{:?}", value);
+    }
+}
+
 #[test]
 fn test_serialize_binary_generic() {
     let _guard = LOCK.run_concurrently();
@@ -322,7 +523,7 @@ fn test_serialize_utc_date_time() {
     #[allow(unused)]
     let src = time::OffsetDateTime::from_unix_timestamp(1_286_705_410).unwrap();
     #[cfg(feature = "chrono-0_4")]
-    let src = chrono::Utc.timestamp(1_286_705_410, 0);
+    let src = chrono::Utc.timestamp_opt(1_286_705_410, 0).unwrap();
     let dst = vec![
         18, 0, 0, 0, 9, 107, 101, 121, 0, 208, 111, 158, 149, 43, 1, 0, 0, 0,
     ];
@@ -571,3 +772,598 @@ fn test_deserialize_invalid_old_binary_length() {
     Document::from_reader(&mut std::io::Cursor::new(buffer))
         .expect_err("expected deserialization to fail");
 }
+
+#[test]
+fn test_decode_truncated_document() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! {
+        "int": 1,
+        "string": "hello",
+        "array": [1, "two", { "three": null }],
+        "binary": Binary { subtype: BinarySubtype::BinaryOld, bytes: vec![1, 2, 3] },
+        "code": JavaScriptCodeWithScope { code: "x".to_string(), scope: doc! { "x": 1 } },
+    };
+    let bytes = crate::encode_document_to_vec(&doc).unwrap();
+
+    for len in 0..bytes.len() {
+        let truncated = &bytes[..len];
+        let error = crate::decode_document_from_slice(truncated)
+            .expect_err("truncated input should fail to decode");
+        if len >= 4 {
+            assert!(
+                matches!(
+                    error,
+                    crate::de::Error::Io(ref e) if e.kind() == std::io::ErrorKind::UnexpectedEof
+                ),
+                "unexpected error for {} of {} bytes: {:?}",
+                len,
+                bytes.len(),
+                error
+            );
+        }
+        crate::from_slice::<Document>(truncated).expect_err("truncated input should fail");
+        assert!(crate::DocumentReader::new(truncated)
+            .next()
+            .map_or(len == 0, |r| r.is_err()));
+    }
+
+    // Length prefixes that claim far more data than is present, or that are negative. None of
+    // these should allocate a buffer of the claimed size.
+    for buffer in [
+        &b"\xff\xff\xff\x7f\x00"[..],
+        &b"\x00\x00\x00\x01\x00"[..],
+        &b"\xff\xff\xff\xff\x00"[..],
+        &b"\x00\x00\x00\x80\x00"[..],
+        &b"\x0c\x00\x00\x00\x02a\x00\xff\xff\xff\x7f\x00"[..],
+        &b"\x0c\x00\x00\x00\x03a\x00\xff\xff\xff\x7f\x00"[..],
+    ] {
+        crate::decode_document_from_slice(buffer).expect_err("invalid length should fail");
+        crate::decode_document(&mut &buffer[..]).expect_err("invalid length should fail");
+        crate::from_slice::<Document>(buffer).expect_err("invalid length should fail");
+        crate::from_reader::<_, Document>(buffer).expect_err("invalid length should fail");
+        assert!(matches!(
+            crate::DocumentReader::new(buffer).next(),
+            Some(Err(_))
+        ));
+    }
+}
+
+#[test]
+fn test_decode_document_larger_than_max_bson_size() {
+    let _guard = LOCK.run_concurrently();
+    // Encoders accept documents up to `MAX_ENCODED_DOCUMENT_SIZE`, so every decoder must read them
+    // back.
+    let doc = doc! {
+        "blob": Binary { subtype: BinarySubtype::Generic, bytes: vec![0; crate::MAX_BSON_SIZE] },
+    };
+    let bytes = crate::to_vec(&doc).unwrap();
+    assert!(bytes.len() > crate::MAX_BSON_SIZE);
+    assert!(bytes.len() <= crate::MAX_ENCODED_DOCUMENT_SIZE);
+
+    assert_eq!(Document::from_reader(bytes.as_slice()).unwrap(), doc);
+    assert_eq!(crate::decode_document(&mut bytes.as_slice()).unwrap(), doc);
+    assert_eq!(crate::decode_document_from_slice(&bytes).unwrap(), doc);
+    assert_eq!(crate::from_slice::<Document>(&bytes).unwrap(), doc);
+    assert_eq!(
+        crate::from_reader::<_, Document>(bytes.as_slice()).unwrap(),
+        doc
+    );
+    assert_eq!(
+        crate::DocumentReader::new(bytes.as_slice())
+            .next()
+            .unwrap()
+            .unwrap(),
+        doc
+    );
+
+    let array = doc! { "array": [doc.get("blob").unwrap().clone()] };
+    let bytes = crate::to_vec(&array).unwrap();
+    assert!(bytes.len() > crate::MAX_BSON_SIZE);
+    assert_eq!(crate::decode_document_from_slice(&bytes).unwrap(), array);
+}
+
+#[test]
+fn test_encode_decode_document_free_functions() {
+    let _guard = LOCK.run_concurrently();
+    let first = doc! { "x": 1, "s": "a" };
+    let second = doc! { "nested": { "y": [1, 2] } };
+
+    let mut buf = Vec::new();
+    crate::encode_document(&mut buf, &first).unwrap();
+    crate::encode_document(&mut buf, &second).unwrap();
+    assert_eq!(
+        crate::encode_document_to_vec(&first).unwrap(),
+        buf[..buf.len() - crate::encode_document_to_vec(&second).unwrap().len()]
+    );
+
+    let mut reader = Cursor::new(buf);
+    assert_eq!(crate::decode_document(&mut reader).unwrap(), first);
+    assert_eq!(crate::decode_document(&mut reader).unwrap(), second);
+    crate::decode_document(&mut reader).unwrap_err();
+
+    let bytes = crate::encode_document_to_vec(&first).unwrap();
+    assert_eq!(crate::decode_document_from_slice(&bytes).unwrap(), first);
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    crate::decode_document_from_slice(&trailing).unwrap_err();
+
+    // length prefix claims more bytes than are available
+    crate::decode_document_from_slice(&bytes[..bytes.len() - 1]).unwrap_err();
+    crate::decode_document_from_slice(&[4, 0, 0, 0]).unwrap_err();
+}
+
+#[test]
+fn test_document_reader() {
+    let _guard = LOCK.run_concurrently();
+    let docs: Vec<Document> = (0..10)
+        .map(|i| doc! { "i": i, "s": "x".repeat(i as usize) })
+        .collect();
+
+    let mut cursor = Cursor::new(Vec::new());
+    for doc in &docs {
+        crate::encode_document(&mut cursor, doc).unwrap();
+    }
+    cursor.set_position(0);
+
+    let read: Vec<Document> = crate::DocumentReader::new(cursor)
+        .collect::<crate::de::Result<_>>()
+        .unwrap();
+    assert_eq!(read, docs);
+
+    assert_eq!(crate::DocumentReader::new(&[][..]).count(), 0);
+
+    // a stream that ends part way through a document yields an error and then stops.
+    let bytes = crate::encode_document_to_vec(&docs[3]).unwrap();
+    let mut truncated = bytes.clone();
+    truncated.extend_from_slice(&bytes[..6]);
+    let mut reader = crate::DocumentReader::new(truncated.as_slice());
+    assert_eq!(reader.next().unwrap().unwrap(), docs[3]);
+    reader.next().unwrap().unwrap_err();
+    assert!(reader.next().is_none());
+
+    let mut reader = crate::DocumentReader::new(&bytes[..2]);
+    assert!(matches!(
+        reader.next(),
+        Some(Err(crate::de::Error::EndOfStream))
+    ));
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_encode_decode_document_async() {
+    let _guard = LOCK.run_concurrently();
+    let docs: Vec<Document> = (0..5)
+        .map(|i| doc! { "i": i, "payload": "x".repeat(100) })
+        .collect();
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        // a buffer smaller than a single document forces both sides to yield part way through.
+        let (mut client, mut server) = tokio::io::duplex(16);
+        let write = async {
+            for doc in &docs {
+                crate::encode_document_async(&mut client, doc)
+                    .await
+                    .unwrap();
+            }
+            drop(client);
+        };
+        let read = async {
+            let mut read = Vec::new();
+            for _ in 0..docs.len() {
+                read.push(crate::decode_document_async(&mut server).await.unwrap());
+            }
+            // the writer has been dropped, so there is nothing left to read.
+            crate::decode_document_async(&mut server).await.unwrap_err();
+            read
+        };
+
+        let ((), read) = tokio::join!(write, read);
+        assert_eq!(read, docs);
+
+        let mut invalid: &[u8] = &[4, 0, 0, 0, 0];
+        crate::decode_document_async(&mut invalid)
+            .await
+            .unwrap_err();
+
+        // an oversized length prefix is rejected before the body is allocated or read.
+        let mut too_large: &[u8] = &[0xff, 0xff, 0xff, 0x7f, 0];
+        let error = crate::decode_document_async(&mut too_large)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, crate::de::Error::DeserializationError { .. }),
+            "{:?}",
+            error
+        );
+        assert_eq!(too_large, &[0]);
+
+        // documents between `MAX_BSON_SIZE` and `MAX_ENCODED_DOCUMENT_SIZE` round-trip.
+        let large = doc! {
+            "blob": Binary { subtype: BinarySubtype::Generic, bytes: vec![0; crate::MAX_BSON_SIZE] },
+        };
+        let mut bytes = Vec::new();
+        crate::encode_document_async(&mut bytes, &large)
+            .await
+            .unwrap();
+        assert!(bytes.len() > crate::MAX_BSON_SIZE);
+        assert_eq!(
+            crate::decode_document_async(&mut bytes.as_slice())
+                .await
+                .unwrap(),
+            large
+        );
+    });
+}
+
+#[cfg(feature = "tokio-util")]
+#[test]
+fn test_bson_document_codec() {
+    use tokio_util::{
+        bytes::BytesMut,
+        codec::{Decoder, Encoder},
+    };
+
+    use crate::codec::BsonDocumentCodec;
+
+    let _guard = LOCK.run_concurrently();
+    let first = doc! { "a": 1 };
+    let second = doc! { "b": "two" };
+
+    let mut codec = BsonDocumentCodec::new();
+    let mut encoded = BytesMut::new();
+    codec.encode(first.clone(), &mut encoded).unwrap();
+    codec.encode(&second, &mut encoded).unwrap();
+
+    // feed the encoded stream one byte at a time to exercise partial frames.
+    let mut buf = BytesMut::new();
+    let mut decoded = Vec::new();
+    for byte in encoded.iter() {
+        buf.extend_from_slice(&[*byte]);
+        if let Some(doc) = codec.decode(&mut buf).unwrap() {
+            decoded.push(doc);
+        }
+    }
+    assert_eq!(decoded, vec![first, second]);
+    assert!(buf.is_empty());
+
+    let mut too_small = BytesMut::from(&[4u8, 0, 0, 0][..]);
+    codec.decode(&mut too_small).unwrap_err();
+
+    let mut too_large = BytesMut::from(&(17 * 1024 * 1024_i32).to_le_bytes()[..]);
+    codec.decode(&mut too_large).unwrap_err();
+
+    // documents up to the limit enforced by `to_vec` and `Document::to_writer` round-trip, and
+    // anything larger is rejected before it is written.
+    let large = doc! {
+        "blob": Binary { subtype: BinarySubtype::Generic, bytes: vec![0; crate::MAX_BSON_SIZE] },
+    };
+    assert!(large.byte_len() > crate::MAX_BSON_SIZE);
+    let mut dst = BytesMut::new();
+    codec.encode(&large, &mut dst).unwrap();
+    assert_eq!(codec.decode(&mut dst).unwrap(), Some(large));
+    assert!(dst.is_empty());
+
+    let too_large = doc! {
+        "blob": Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: vec![0; crate::MAX_ENCODED_DOCUMENT_SIZE],
+        },
+    };
+    match codec.encode(&too_large, &mut dst) {
+        Err(crate::ser::Error::DocumentTooLarge { actual, limit }) => {
+            assert_eq!(actual, too_large.byte_len());
+            assert_eq!(limit, crate::MAX_ENCODED_DOCUMENT_SIZE);
+        }
+        other => panic!("expected DocumentTooLarge, got {:?}", other),
+    }
+    assert!(dst.is_empty());
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_encode_decode_document_bytes() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! { "a": 1, "b": { "c": [true, "d"] } };
+
+    let bytes = crate::encode_document_to_bytes(&doc).unwrap();
+    assert_eq!(&bytes[..], crate::to_vec(&doc).unwrap().as_slice());
+    assert_eq!(
+        crate::decode_document_from_bytes(bytes.clone()).unwrap(),
+        doc
+    );
+
+    let mut trailing = bytes::BytesMut::from(&bytes[..]);
+    trailing.extend_from_slice(&[0]);
+    crate::decode_document_from_bytes(trailing.freeze()).unwrap_err();
+    crate::decode_document_from_bytes(bytes.slice(..bytes.len() - 1)).unwrap_err();
+}
+
+#[test]
+fn test_serialize_document_too_large() {
+    let _guard = LOCK.run_concurrently();
+    let blob = Binary {
+        subtype: BinarySubtype::Generic,
+        bytes: vec![0; 9 * 1024 * 1024],
+    };
+    let doc = doc! { "a": blob.clone(), "b": blob };
+    let expected = doc.byte_len();
+
+    let mut buf = Vec::new();
+    match doc.to_writer(&mut buf) {
+        Err(Error::DocumentTooLarge { actual, limit }) => {
+            assert_eq!(actual, expected);
+            assert_eq!(limit, 16_793_600);
+        }
+        other => panic!("expected DocumentTooLarge, got {:?}", other),
+    }
+    assert!(buf.is_empty());
+
+    assert!(matches!(
+        crate::to_vec(&doc),
+        Err(Error::DocumentTooLarge { actual, .. }) if actual == expected
+    ));
+}
+
+#[test]
+fn test_deserialize_error_path() {
+    use crate::de::PathSegment;
+
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct User {
+        email: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Users {
+        users: Vec<User>,
+    }
+
+    let doc = doc! {
+        "users": [
+            { "email": "a@example.com" },
+            { "email": "b@example.com" },
+            { "email": 5 },
+        ]
+    };
+    let expected_path = vec![
+        PathSegment::Key("users".to_string()),
+        PathSegment::Index(2),
+        PathSegment::Key("email".to_string()),
+    ];
+
+    let bytes = crate::to_vec(&doc).unwrap();
+    let raw_error = crate::from_slice::<Users>(&bytes).unwrap_err();
+    let doc_error = from_document::<Users>(doc).unwrap_err();
+
+    for error in [raw_error, doc_error] {
+        assert_eq!(error.path(), expected_path.as_slice());
+        assert!(
+            error
+                .to_string()
+                .starts_with(".users[2].email: invalid type"),
+            "{}",
+            error
+        );
+    }
+
+    let top_level = from_document::<User>(doc! { "email": 1 }).unwrap_err();
+    assert_eq!(top_level.path(), &[PathSegment::Key("email".into())]);
+    let missing = from_document::<User>(doc! {}).unwrap_err();
+    assert!(missing.path().is_empty());
+    assert_eq!(missing.to_string(), "missing field `email`");
+}
+
+#[test]
+fn test_error_source() {
+    use std::{error::Error as _, io};
+
+    let _guard = LOCK.run_concurrently();
+
+    struct Failing;
+
+    impl io::Read for Failing {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "read failed"))
+        }
+    }
+
+    impl io::Write for Failing {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "write failed"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let ser_error = doc! { "x": 1 }.to_writer(Failing).unwrap_err();
+    assert_eq!(ser_error.to_string(), "write failed");
+    let source = ser_error.source().unwrap();
+    assert_eq!(
+        source.downcast_ref::<io::Error>().unwrap().kind(),
+        io::ErrorKind::BrokenPipe
+    );
+
+    let de_error = Document::from_reader(Failing).unwrap_err();
+    assert_eq!(de_error.to_string(), "read failed");
+    let source = de_error.source().unwrap();
+    assert_eq!(
+        source.downcast_ref::<io::Error>().unwrap().kind(),
+        io::ErrorKind::BrokenPipe
+    );
+
+    assert!(Error::UnsignedIntegerExceededRange(u64::MAX)
+        .source()
+        .is_none());
+}
+
+#[test]
+fn test_deserialize_wrong_type_is_recoverable() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct WithOid {
+        id: ObjectId,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct WithDoc {
+        doc: Document,
+    }
+
+    let wrong_values = [
+        Bson::Int32(1),
+        Bson::String("not an oid".into()),
+        Bson::Array(vec![]),
+        Bson::Null,
+        Bson::Document(doc! { "a": 1 }),
+        Bson::DateTime(crate::DateTime::from_millis(0)),
+    ];
+
+    for value in wrong_values.iter() {
+        let doc = doc! { "id": value.clone() };
+        from_document::<WithOid>(doc.clone()).unwrap_err();
+        crate::from_slice::<WithOid>(&crate::to_vec(&doc).unwrap()).unwrap_err();
+
+        if !matches!(value, Bson::Document(_)) {
+            let doc = doc! { "doc": value.clone() };
+            from_document::<WithDoc>(doc.clone()).unwrap_err();
+            crate::from_slice::<WithDoc>(&crate::to_vec(&doc).unwrap()).unwrap_err();
+        }
+    }
+}
+
+#[test]
+fn test_deserialize_unknown_fields_interspersed() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Known {
+        a: i32,
+        b: String,
+        c: Vec<i32>,
+    }
+
+    let doc = doc! {
+        "x": "ignored",
+        "a": 1,
+        "y": { "nested": [1, 2, 3] },
+        "z": Bson::Null,
+        "b": "two",
+        "w": 4.5,
+        "c": [3],
+        "v": "trailing",
+    };
+    let expected = Known {
+        a: 1,
+        b: "two".to_string(),
+        c: vec![3],
+    };
+
+    assert_eq!(from_document::<Known>(doc.clone()).unwrap(), expected);
+    assert_eq!(
+        crate::from_slice::<Known>(&crate::to_vec(&doc).unwrap()).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_deserialize_deny_unknown_fields() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(deny_unknown_fields)]
+    struct Strict {
+        a: i32,
+    }
+
+    let valid = doc! { "a": 1 };
+    assert_eq!(
+        from_document::<Strict>(valid.clone()).unwrap(),
+        Strict { a: 1 }
+    );
+    assert_eq!(
+        crate::from_slice::<Strict>(&crate::to_vec(&valid).unwrap()).unwrap(),
+        Strict { a: 1 }
+    );
+
+    let extra = doc! { "a": 1, "extra": true };
+    let errors = [
+        from_document::<Strict>(extra.clone()).unwrap_err(),
+        crate::from_slice::<Strict>(&crate::to_vec(&extra).unwrap()).unwrap_err(),
+    ];
+    for error in errors.iter() {
+        assert!(
+            error.to_string().contains("unknown field `extra`"),
+            "{}",
+            error
+        );
+        match error {
+            crate::de::Error::UnknownField {
+                field, expected, ..
+            } => {
+                assert_eq!(field, "extra");
+                assert_eq!(*expected, ["a"]);
+            }
+            other => panic!("expected UnknownField, got {:?}", other),
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Outer {
+        #[allow(dead_code)]
+        inner: Vec<Strict>,
+    }
+
+    let nested = doc! { "inner": [{ "a": 1 }, { "a": 2, "extra": true }] };
+    let error = from_document::<Outer>(nested).unwrap_err();
+    assert_eq!(
+        error.path(),
+        &[
+            crate::de::PathSegment::Key("inner".to_string()),
+            crate::de::PathSegment::Index(1),
+        ]
+    );
+    assert_eq!(
+        error.to_string(),
+        ".inner[1]: unknown field `extra`, expected `a`"
+    );
+}
+
+#[test]
+fn test_encode_decode_array() {
+    let _guard = LOCK.run_concurrently();
+    let items = vec![Bson::Int32(1), Bson::String("two".into()), Bson::Null];
+
+    let bytes = crate::encode_array_to_vec(&items).unwrap();
+    assert_eq!(
+        bytes,
+        crate::encode_document_to_vec(&doc! { "0": 1, "1": "two", "2": null }).unwrap()
+    );
+    assert_eq!(crate::decode_array_from_slice(&bytes).unwrap(), items);
+
+    let empty = crate::encode_array_to_vec(&[]).unwrap();
+    assert_eq!(empty, vec![5, 0, 0, 0, 0]);
+    assert!(crate::decode_array_from_slice(&empty).unwrap().is_empty());
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(crate::decode_array_from_slice(&trailing).is_err());
+    assert!(crate::decode_array_from_slice(&bytes[..bytes.len() - 1]).is_err());
+
+    assert_eq!(
+        crate::to_bson(&vec![1, 2]).unwrap(),
+        Bson::Array(vec![Bson::Int32(1), Bson::Int32(2)])
+    );
+}
@@ -1,4 +1,4 @@
-#![allow(clippy::blacklisted_name)]
+#![allow(clippy::disallowed_names)]
 
 use crate::{
     bson,
@@ -418,6 +418,413 @@ fn test_serde_bytes() {
     assert_eq!(x, f);
 }
 
+#[test]
+fn test_bson_visitor_bytes() {
+    let _guard = LOCK.run_concurrently();
+    let expected = Bson::Binary(Binary {
+        subtype: BinarySubtype::Generic,
+        bytes: b"12345abcde".to_vec(),
+    });
+
+    let borrowed: Bson = Deserialize::deserialize(serde::de::value::BytesDeserializer::<
+        serde::de::value::Error,
+    >::new(b"12345abcde"))
+    .unwrap();
+    assert_eq!(borrowed, expected);
+
+    let owned = serde::de::Visitor::visit_byte_buf::<serde::de::value::Error>(
+        crate::de::BsonVisitor,
+        b"12345abcde".to_vec(),
+    )
+    .unwrap();
+    assert_eq!(owned, expected);
+
+    let bytes = crate::to_vec(&doc! { "data": expected.clone() }).unwrap();
+    let doc: Document = crate::from_slice(&bytes).unwrap();
+    assert_eq!(doc.get("data"), Some(&expected));
+}
+
+#[test]
+fn test_bson_visitor_u32() {
+    let _guard = LOCK.run_concurrently();
+    use serde::de::value::{Error, U32Deserializer};
+
+    let visit = |value: u32| -> Bson {
+        Deserialize::deserialize(U32Deserializer::<Error>::new(value)).unwrap()
+    };
+
+    assert_eq!(visit(0), Bson::Int32(0));
+    assert_eq!(visit(i32::MAX as u32), Bson::Int32(i32::MAX));
+    assert_eq!(visit(i32::MAX as u32 + 1), Bson::Int64(i32::MAX as i64 + 1));
+    assert_eq!(visit(u32::MAX), Bson::Int64(u32::MAX as i64));
+}
+
+#[test]
+fn test_bson_visitor_mixed_seq() {
+    let _guard = LOCK.run_concurrently();
+    let oid = ObjectId::new();
+    let expected = Bson::Array(vec![
+        Bson::Int32(1),
+        Bson::String("two".into()),
+        Bson::Boolean(true),
+        Bson::Null,
+        Bson::Double(1.5),
+        Bson::Int64(i64::MAX),
+        Bson::ObjectId(oid),
+        Bson::Array(vec![Bson::Document(doc! { "x": 1 })]),
+    ]);
+
+    // a non-BSON deserializer drives BsonVisitor::visit_seq directly
+    let json = json!([1, "two", true, null, 1.5, i64::MAX, { "$oid": oid.to_hex() }, [{ "x": 1 }]]);
+    let from_json: Bson = serde_json::from_value(json).unwrap();
+    assert_eq!(from_json, expected);
+
+    let bytes = crate::to_vec(&doc! { "array": expected.clone() }).unwrap();
+    let from_bson: Document = crate::from_slice(&bytes).unwrap();
+    assert_eq!(from_bson.get("array"), Some(&expected));
+}
+
+#[test]
+fn test_bson_visitor_extjson_numbers() {
+    let _guard = LOCK.run_concurrently();
+    let parse = |value: serde_json::Value| serde_json::from_value::<Bson>(value);
+
+    assert_eq!(
+        parse(json!({ "$numberInt": "42" })).unwrap(),
+        Bson::Int32(42)
+    );
+    assert_eq!(
+        parse(json!({ "$numberLong": "9007199254740993" })).unwrap(),
+        Bson::Int64(9007199254740993)
+    );
+    assert_eq!(
+        parse(json!({ "$numberDouble": "1.5" })).unwrap(),
+        Bson::Double(1.5)
+    );
+    assert_eq!(
+        parse(json!({ "$numberDouble": "Infinity" })).unwrap(),
+        Bson::Double(f64::INFINITY)
+    );
+    match parse(json!({ "$numberDouble": "-0.0" })).unwrap() {
+        Bson::Double(d) => assert_eq!(d.to_bits(), (-0.0f64).to_bits()),
+        other => panic!("expected double, got {:?}", other),
+    }
+    assert_eq!(
+        parse(json!({ "$numberDecimal": "1.5" })).unwrap(),
+        Bson::Decimal128("1.5".parse().unwrap())
+    );
+
+    assert!(parse(json!({ "$numberInt": "forty-two" })).is_err());
+    assert!(parse(json!({ "$numberDouble": "one and a half" })).is_err());
+    assert!(parse(json!({ "$numberDecimal": "not a number" })).is_err());
+}
+
+#[test]
+fn test_bson_serialize_as_extjson() {
+    let _guard = LOCK.run_concurrently();
+    let oid = ObjectId::parse_str("507f1f77bcf86cd799439011").unwrap();
+    let decimal: crate::Decimal128 = "1.5".parse().unwrap();
+    let cases = vec![
+        (Bson::Int32(1), json!(1)),
+        (Bson::Int64(i64::MAX), json!(i64::MAX)),
+        (Bson::Double(1.5), json!(1.5)),
+        (Bson::String("s".into()), json!("s")),
+        (Bson::Null, json!(null)),
+        (bson!([1, "two"]), json!([1, "two"])),
+        (
+            Bson::ObjectId(oid),
+            json!({ "$oid": "507f1f77bcf86cd799439011" }),
+        ),
+        (
+            Bson::DateTime(DateTime::from_millis(5)),
+            json!({ "$date": { "$numberLong": "5" } }),
+        ),
+        (
+            Bson::Binary(Binary {
+                subtype: BinarySubtype::Generic,
+                bytes: vec![1, 2, 3],
+            }),
+            json!({ "$binary": { "base64": "AQID", "subType": "00" } }),
+        ),
+        (
+            Bson::Decimal128(decimal),
+            json!({ "$numberDecimal": "1.5" }),
+        ),
+        (
+            Bson::Timestamp(Timestamp {
+                time: 1,
+                increment: 2,
+            }),
+            json!({ "$timestamp": { "t": 1, "i": 2 } }),
+        ),
+        (Bson::MinKey, json!({ "$minKey": 1 })),
+    ];
+
+    for (bson, expected) in cases {
+        let value = serde_json::to_value(&bson).unwrap();
+        assert_eq!(value, expected, "{:?}", bson);
+        assert_eq!(Bson::try_from(value).unwrap(), bson);
+
+        // the BSON serializer still round-trips the value exactly
+        let doc = doc! { "v": bson.clone() };
+        assert_eq!(to_document(&doc).unwrap(), doc);
+        let from_bytes: Document = crate::from_slice(&crate::to_vec(&doc).unwrap()).unwrap();
+        assert_eq!(from_bytes, doc);
+    }
+}
+
+#[test]
+fn test_bson_visitor_primitives() {
+    use serde::de::{
+        value::{BytesDeserializer, Error as ValueError, UnitDeserializer},
+        IntoDeserializer,
+    };
+
+    let _guard = LOCK.run_concurrently();
+    fn from<'de, D>(deserializer: D) -> Result<Bson, ValueError>
+    where
+        D: serde::Deserializer<'de, Error = ValueError>,
+    {
+        Bson::deserialize(deserializer)
+    }
+
+    assert_eq!(from(true.into_deserializer()).unwrap(), Bson::Boolean(true));
+    assert_eq!(from(5i8.into_deserializer()).unwrap(), Bson::Int32(5));
+    assert_eq!(from(5u8.into_deserializer()).unwrap(), Bson::Int32(5));
+    assert_eq!(from(5i16.into_deserializer()).unwrap(), Bson::Int32(5));
+    assert_eq!(from(5u16.into_deserializer()).unwrap(), Bson::Int32(5));
+    assert_eq!(from(5i32.into_deserializer()).unwrap(), Bson::Int32(5));
+    assert_eq!(from(5u32.into_deserializer()).unwrap(), Bson::Int32(5));
+    assert_eq!(from(5i64.into_deserializer()).unwrap(), Bson::Int64(5));
+    assert_eq!(from(5u64.into_deserializer()).unwrap(), Bson::Int32(5));
+    assert_eq!(
+        from((i64::MAX as u64).into_deserializer()).unwrap(),
+        Bson::Int64(i64::MAX)
+    );
+    assert!(from(u64::MAX.into_deserializer()).is_err());
+    assert_eq!(from(1.5f32.into_deserializer()).unwrap(), Bson::Double(1.5));
+    assert_eq!(from(1.5f64.into_deserializer()).unwrap(), Bson::Double(1.5));
+    assert_eq!(
+        from('c'.into_deserializer()).unwrap(),
+        Bson::String("c".to_string())
+    );
+    assert_eq!(
+        from("s".into_deserializer()).unwrap(),
+        Bson::String("s".to_string())
+    );
+    assert_eq!(
+        from(String::from("s").into_deserializer()).unwrap(),
+        Bson::String("s".to_string())
+    );
+    assert_eq!(
+        from(BytesDeserializer::new(&[1, 2])).unwrap(),
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: vec![1, 2],
+        })
+    );
+    assert_eq!(from(UnitDeserializer::new()).unwrap(), Bson::Null);
+
+    assert_eq!(serde_json::from_str::<Bson>("null").unwrap(), Bson::Null);
+}
+
+#[test]
+fn test_bson_serialize_binary_and_decimal_to_bson() {
+    let _guard = LOCK.run_concurrently();
+    let mut negative_nan = [0; 16];
+    negative_nan[15] = 0xfc;
+    let cases = vec![
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: vec![1, 2, 3],
+        }),
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::UserDefined(0x80),
+            bytes: vec![4, 5],
+        }),
+        Bson::Decimal128("1.5".parse().unwrap()),
+        Bson::Decimal128(crate::Decimal128::from_bytes(negative_nan)),
+    ];
+
+    for bson in cases {
+        assert_eq!(to_bson(&bson).unwrap(), bson);
+        let serializer = Serializer::new_with_options(
+            crate::SerializerOptions::builder()
+                .human_readable(false)
+                .build(),
+        );
+        assert_eq!(bson.serialize(serializer).unwrap(), bson);
+    }
+}
+
+#[test]
+fn test_serde_f32() {
+    let _guard = LOCK.run_concurrently();
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo {
+        x: f32,
+    }
+
+    let foo = Foo { x: 0.1 };
+    let doc = to_document(&foo).unwrap();
+    assert_eq!(doc, doc! { "x": 0.1f32 as f64 });
+    assert_ne!(doc, doc! { "x": 0.1 });
+    let bytes = crate::to_vec(&foo).unwrap();
+    assert_eq!(Document::from_reader(bytes.as_slice()).unwrap(), doc);
+    assert_eq!(crate::from_slice::<Foo>(&bytes).unwrap(), foo);
+    assert_eq!(from_document::<Foo>(doc).unwrap(), foo);
+
+    let visited =
+        serde::de::Visitor::visit_f32::<serde::de::value::Error>(crate::de::BsonVisitor, 1.5)
+            .unwrap();
+    assert_eq!(visited, Bson::Double(1.5));
+}
+
+#[test]
+fn test_deserialize_seed() {
+    let _guard = LOCK.run_concurrently();
+    use serde::de::{DeserializeSeed, Deserializer};
+
+    // Collects every string it deserializes into a shared buffer rather than returning it.
+    struct Collect<'a>(&'a mut Vec<String>);
+
+    impl<'de, 'a> DeserializeSeed<'de> for Collect<'a> {
+        type Value = usize;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+            let strings: BTreeMap<String, String> = Deserialize::deserialize(deserializer)?;
+            let count = strings.len();
+            self.0.extend(strings.into_values());
+            Ok(count)
+        }
+    }
+
+    let doc = doc! { "a": "x", "b": "y" };
+    let mut seen = Vec::new();
+
+    let bytes = crate::to_vec(&doc).unwrap();
+    assert_eq!(
+        crate::from_slice_seed(&bytes, Collect(&mut seen)).unwrap(),
+        2
+    );
+
+    let count = crate::Deserializer::new(Bson::Document(doc))
+        .deserialize_seed(Collect(&mut seen))
+        .unwrap();
+    assert_eq!(count, 2);
+
+    seen.sort();
+    assert_eq!(seen, vec!["x", "x", "y", "y"]);
+
+    assert!(crate::from_slice_seed(&bytes[..4], Collect(&mut seen)).is_err());
+}
+
+#[test]
+fn test_deserialize_array_length_mismatch() {
+    let _guard = LOCK.run_concurrently();
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Foo {
+        t: (i32, i32),
+    }
+
+    assert_eq!(
+        from_document::<Foo>(doc! { "t": [1, 2] }).unwrap(),
+        Foo { t: (1, 2) }
+    );
+
+    let long = doc! { "t": [1, 2, 3] };
+    let error = from_document::<Foo>(long.clone()).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("invalid length 3, expected 2 elements in array"),
+        "{}",
+        error
+    );
+    assert!(crate::from_slice::<Foo>(&crate::to_vec(&long).unwrap()).is_err());
+
+    let short = doc! { "t": [1] };
+    assert!(from_document::<Foo>(short.clone()).is_err());
+    assert!(crate::from_slice::<Foo>(&crate::to_vec(&short).unwrap()).is_err());
+}
+
+#[test]
+fn test_deserialize_malformed_enum_variants() {
+    let _guard = LOCK.run_concurrently();
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum Foo {
+        Unit,
+        Newtype(i32),
+        Tuple(i32, i32),
+        Struct { a: i32 },
+    }
+
+    let cases = [
+        (
+            Bson::String("Newtype".into()),
+            "invalid type: unit variant, expected newtype variant",
+        ),
+        (
+            Bson::String("Tuple".into()),
+            "invalid type: unit variant, expected tuple variant",
+        ),
+        (
+            Bson::String("Struct".into()),
+            "invalid type: unit variant, expected struct variant",
+        ),
+        (
+            bson!({ "Tuple": "x" }),
+            "invalid type: string \"x\", expected an array of tuple variant fields",
+        ),
+        (
+            bson!({ "Struct": 1 }),
+            "invalid type: integer `1`, expected a document of struct variant fields",
+        ),
+        (bson!(1), "invalid type: integer `1`, expected an enum"),
+    ];
+    for (bson, message) in cases {
+        let error = from_bson::<Foo>(bson.clone()).unwrap_err();
+        assert!(error.to_string().contains(message), "{:?}: {}", bson, error);
+    }
+
+    assert_eq!(
+        from_bson::<Foo>(Bson::String("Unit".into())).unwrap(),
+        Foo::Unit
+    );
+    assert_eq!(
+        from_bson::<Foo>(bson!({ "Tuple": [1, 2] })).unwrap(),
+        Foo::Tuple(1, 2)
+    );
+}
+
+#[test]
+fn test_serde_char() {
+    let _guard = LOCK.run_concurrently();
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct Foo {
+        initial: char,
+        symbol: char,
+    }
+
+    let x = Foo {
+        initial: 'b',
+        symbol: '\u{1F980}',
+    };
+
+    let doc = to_document(&x).unwrap();
+    assert_eq!(doc, doc! { "initial": "b", "symbol": "\u{1F980}" });
+    assert_eq!(from_document::<Foo>(doc.clone()).unwrap(), x);
+
+    let bytes = crate::to_vec(&x).unwrap();
+    assert_eq!(Document::from_reader(bytes.as_slice()).unwrap(), doc);
+    assert_eq!(crate::from_slice::<Foo>(&bytes).unwrap(), x);
+
+    let visited =
+        serde::de::Visitor::visit_char::<serde::de::value::Error>(crate::de::BsonVisitor, 'b')
+            .unwrap();
+    assert_eq!(visited, Bson::String("b".to_string()));
+}
+
 #[test]
 fn test_serde_newtype_struct() {
     let _guard = LOCK.run_concurrently();
@@ -553,6 +960,37 @@ fn test_de_db_pointer() {
     assert_eq!(foo.db_pointer, db_pointer.clone());
 }
 
+#[test]
+fn test_db_pointer_round_trip() {
+    let _guard = LOCK.run_concurrently();
+    use bson::DbPointer;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Foo {
+        db_pointer: DbPointer,
+    }
+
+    let extjson = json!({
+        "$dbPointer": {
+            "$ref": "db.coll",
+            "$id": { "$oid": "507f1f77bcf86cd799439011" },
+        }
+    });
+    let bson = Bson::try_from(extjson.clone()).unwrap();
+    assert_eq!(bson.clone().into_canonical_extjson(), extjson);
+    assert_eq!(bson.clone().into_relaxed_extjson(), extjson);
+
+    let foo = Foo {
+        db_pointer: bson.as_db_pointer().unwrap().clone(),
+    };
+    let bytes = crate::to_vec(&foo).unwrap();
+    let doc = Document::from_reader(bytes.as_slice()).unwrap();
+    assert_eq!(doc, doc! { "db_pointer": bson.clone() });
+    assert_eq!(crate::to_vec(&doc).unwrap(), bytes);
+    assert_eq!(crate::from_slice::<Foo>(&bytes).unwrap(), foo);
+    assert_eq!(from_document::<Foo>(doc).unwrap(), foo);
+}
+
 #[cfg(feature = "uuid-0_8")]
 #[test]
 fn test_serde_legacy_uuid_0_8() {
@@ -1006,6 +1444,122 @@ fn large_dates() {
     );
 }
 
+#[test]
+fn binary_as_base64_string() {
+    use serde_helpers::binary_as_base64_string;
+
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Foo {
+        #[serde(with = "binary_as_base64_string")]
+        standard: Binary,
+        #[serde(with = "binary_as_base64_string::url_safe")]
+        url_safe: Binary,
+    }
+
+    let binary = Binary {
+        subtype: BinarySubtype::Generic,
+        bytes: vec![0xfb, 0xef, 0xff],
+    };
+    let foo = Foo {
+        standard: binary.clone(),
+        url_safe: binary.clone(),
+    };
+
+    let doc = to_document(&foo).unwrap();
+    assert_eq!(doc, doc! { "standard": "++//", "url_safe": "--__" });
+    assert_eq!(from_document::<Foo>(doc).unwrap(), foo);
+
+    let bson = Bson::Binary(binary);
+    assert_eq!(bson.as_binary_bytes(), Some(&[0xfb, 0xef, 0xff][..]));
+    assert_eq!(Bson::String("++//".to_string()).as_binary_bytes(), None);
+
+    let uuid = Bson::Binary(Binary {
+        subtype: BinarySubtype::Uuid,
+        bytes: vec![1; 16],
+    });
+    assert_eq!(uuid.as_binary_bytes(), Some(&[1; 16][..]));
+
+    let wrong_alphabet = doc! { "standard": "--__", "url_safe": "--__" };
+    let error = from_document::<Foo>(wrong_alphabet).unwrap_err();
+    assert!(error.to_string().contains("cannot decode base64"));
+}
+
+#[test]
+fn datetime_serde_modules() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Foo {
+        #[serde(with = "crate::datetime::serde_rfc3339")]
+        rfc3339: DateTime,
+        #[serde(with = "crate::datetime::serde_as_millis")]
+        millis: DateTime,
+    }
+
+    let date = DateTime::from_millis(1_600_000_000_123);
+    let foo = Foo {
+        rfc3339: date,
+        millis: date,
+    };
+
+    let doc = to_document(&foo).unwrap();
+    assert_eq!(
+        doc,
+        doc! { "rfc3339": "2020-09-13T12:26:40.123Z", "millis": 1_600_000_000_123i64 }
+    );
+    assert_eq!(from_document::<Foo>(doc).unwrap(), foo);
+
+    let json = serde_json::to_value(&foo).unwrap();
+    assert_eq!(
+        json,
+        json!({ "rfc3339": "2020-09-13T12:26:40.123Z", "millis": 1_600_000_000_123i64 })
+    );
+    assert_eq!(serde_json::from_value::<Foo>(json).unwrap(), foo);
+
+    let negative = doc! { "rfc3339": "1969-12-31T23:59:59.999Z", "millis": -1i64 };
+    let foo: Foo = from_document(negative).unwrap();
+    assert_eq!(foo.rfc3339, DateTime::from_millis(-1));
+    assert_eq!(foo.millis, DateTime::from_millis(-1));
+}
+
+#[test]
+fn oid_serde_string() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Foo {
+        #[serde(with = "crate::oid::serde_string")]
+        oid: ObjectId,
+        #[serde(with = "serde_helpers::object_id_as_hex_string")]
+        other: ObjectId,
+    }
+
+    let foo = Foo {
+        oid: ObjectId::new(),
+        other: ObjectId::new(),
+    };
+    let doc = to_document(&foo).unwrap();
+    assert_eq!(doc.get_str("oid").unwrap(), foo.oid.to_hex());
+    assert_eq!(doc.get_str("other").unwrap(), foo.other.to_hex());
+    assert_eq!(from_document::<Foo>(doc).unwrap(), foo);
+
+    let json = serde_json::to_value(&foo).unwrap();
+    assert_eq!(
+        json,
+        json!({ "oid": foo.oid.to_hex(), "other": foo.other.to_hex() })
+    );
+    assert_eq!(serde_json::from_value::<Foo>(json).unwrap(), foo);
+
+    let invalid = doc! { "oid": "not an oid", "other": foo.other.to_hex() };
+    let error = from_document::<Foo>(invalid).unwrap_err();
+    assert!(error.to_string().contains("24-character hex string"));
+
+    let object_ids = doc! { "oid": foo.oid, "other": foo.other };
+    from_document::<Foo>(object_ids).unwrap_err();
+}
+
 #[test]
 fn oid_as_hex_string() {
     let _guard = LOCK.run_concurrently();
@@ -1021,3 +1575,223 @@ fn oid_as_hex_string() {
     let doc = to_document(&foo).unwrap();
     assert_eq!(doc.get_str("oid").unwrap(), oid.to_hex());
 }
+
+#[test]
+fn test_flatten() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Metadata {
+        created: DateTime,
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Item {
+        #[serde(rename = "_id")]
+        id: ObjectId,
+        #[serde(flatten)]
+        metadata: Metadata,
+        #[serde(flatten)]
+        rest: Document,
+    }
+
+    let item = Item {
+        id: ObjectId::new(),
+        metadata: Metadata {
+            created: DateTime::from_millis(1_000),
+            tags: vec!["a".to_string()],
+        },
+        rest: doc! { "count": 3, "nested": { "x": 1.5 } },
+    };
+    let doc = doc! {
+        "_id": item.id,
+        "created": DateTime::from_millis(1_000),
+        "tags": ["a"],
+        "count": 3,
+        "nested": { "x": 1.5 },
+    };
+
+    assert_eq!(to_document(&item).unwrap(), doc);
+    assert_eq!(from_document::<Item>(doc.clone()).unwrap(), item);
+    assert_eq!(
+        crate::from_slice::<Item>(&crate::to_vec(&doc).unwrap()).unwrap(),
+        item
+    );
+}
+
+#[test]
+fn test_bson_from_human_readable_format() {
+    let _guard = LOCK.run_concurrently();
+    let from_json = |value: serde_json::Value| -> Bson { serde_json::from_value(value).unwrap() };
+
+    assert_eq!(from_json(json!("hello")), Bson::String("hello".to_string()));
+    assert_eq!(from_json(json!(true)), Bson::Boolean(true));
+    assert_eq!(from_json(json!(null)), Bson::Null);
+    assert_eq!(from_json(json!(1.5)), Bson::Double(1.5));
+    assert_eq!(from_json(json!(1)), Bson::Int32(1));
+    assert_eq!(from_json(json!(i64::MAX)), Bson::Int64(i64::MAX));
+    assert_eq!(from_json(json!(-1)), Bson::Int64(-1));
+
+    // a plain string that happens to look like an ObjectId stays a string
+    let hex = ObjectId::new().to_hex();
+    assert_eq!(from_json(json!(hex)), Bson::String(hex));
+
+    // the BSON deserializers preserve the exact integer width
+    let doc = doc! { "small": Bson::Int64(1), "int": 1 };
+    assert_eq!(crate::from_document::<Document>(doc.clone()).unwrap(), doc);
+    let bytes = crate::to_vec(&doc).unwrap();
+    assert_eq!(crate::from_slice::<Document>(&bytes).unwrap(), doc);
+}
+
+#[test]
+fn test_tagged_enums() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(tag = "type")]
+    enum Internal {
+        Unit,
+        Struct { id: ObjectId, at: DateTime, n: i64 },
+        Newtype(Inner),
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(tag = "t", content = "c")]
+    enum Adjacent {
+        Unit,
+        Newtype(ObjectId),
+        Tuple(i32, String),
+        Struct { at: DateTime },
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(untagged)]
+    enum Untagged {
+        Id { id: ObjectId },
+        Time { at: DateTime },
+        Binary { bytes: Binary },
+        Number(i64),
+        Text(String),
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Inner {
+        doc: Document,
+    }
+
+    fn round_trip<T>(value: T, expected: Bson)
+    where
+        T: Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+    {
+        assert_eq!(to_bson(&value).unwrap(), expected);
+        assert_eq!(from_bson::<T>(expected.clone()).unwrap(), value);
+
+        let wrapper = doc! { "v": expected };
+        let bytes = crate::to_vec(&wrapper).unwrap();
+        let decoded: Document = crate::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, wrapper);
+
+        #[derive(Deserialize)]
+        struct Wrapper<T> {
+            v: T,
+        }
+        assert_eq!(crate::from_slice::<Wrapper<T>>(&bytes).unwrap().v, value);
+    }
+
+    let id = ObjectId::new();
+    let at = DateTime::from_millis(1_600_000_000_000);
+
+    round_trip(Internal::Unit, bson!({ "type": "Unit" }));
+    round_trip(
+        Internal::Struct {
+            id,
+            at,
+            n: i64::MAX,
+        },
+        bson!({ "type": "Struct", "id": id, "at": at, "n": i64::MAX }),
+    );
+    round_trip(
+        Internal::Newtype(Inner {
+            doc: doc! { "x": 1, "y": [at] },
+        }),
+        bson!({ "type": "Newtype", "doc": { "x": 1, "y": [at] } }),
+    );
+
+    round_trip(Adjacent::Unit, bson!({ "t": "Unit" }));
+    round_trip(Adjacent::Newtype(id), bson!({ "t": "Newtype", "c": id }));
+    round_trip(
+        Adjacent::Tuple(1, "two".to_string()),
+        bson!({ "t": "Tuple", "c": [1, "two"] }),
+    );
+    round_trip(
+        Adjacent::Struct { at },
+        bson!({ "t": "Struct", "c": { "at": at } }),
+    );
+
+    round_trip(Untagged::Id { id }, bson!({ "id": id }));
+    round_trip(Untagged::Time { at }, bson!({ "at": at }));
+    let binary = Binary {
+        subtype: BinarySubtype::Generic,
+        bytes: vec![1, 2, 3],
+    };
+    round_trip(
+        Untagged::Binary {
+            bytes: binary.clone(),
+        },
+        bson!({ "bytes": binary }),
+    );
+    round_trip(Untagged::Number(i64::MAX), Bson::Int64(i64::MAX));
+    round_trip(Untagged::Text("hi".into()), Bson::String("hi".into()));
+}
+
+#[test]
+fn test_serialize_struct_field_order() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize)]
+    struct Inner {
+        second: i32,
+        first: i32,
+    }
+
+    #[derive(Serialize)]
+    struct Foo {
+        zebra: i32,
+        apple: i32,
+        mango: Inner,
+        #[serde(rename = "banana")]
+        yam: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        skipped: Option<i32>,
+        cherry: i32,
+    }
+
+    let foo = Foo {
+        zebra: 1,
+        apple: 2,
+        mango: Inner {
+            second: 3,
+            first: 4,
+        },
+        yam: 5,
+        skipped: None,
+        cherry: 6,
+    };
+    let expected = ["zebra", "apple", "mango", "banana", "cherry"];
+
+    let doc = to_document(&foo).unwrap();
+    assert_eq!(doc.keys().collect::<Vec<_>>(), expected);
+    assert_eq!(
+        doc.get_document("mango")
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>(),
+        ["second", "first"]
+    );
+
+    let bytes = crate::to_vec(&foo).unwrap();
+    let raw = crate::RawDocument::from_bytes(&bytes).unwrap();
+    let raw_keys: Vec<&str> = raw.iter().map(|e| e.unwrap().0).collect();
+    assert_eq!(raw_keys, expected);
+}
@@ -139,13 +139,19 @@
 mod test;
 
 use std::{
+    convert::TryFrom,
     fmt::{self, Display},
     str::FromStr,
 };
 
 use serde::{Deserialize, Serialize};
 
-use crate::{de::BsonVisitor, spec::BinarySubtype, Binary, Bson};
+use crate::{
+    de::BsonVisitor,
+    spec::{BinarySubtype, ElementType},
+    Binary,
+    Bson,
+};
 
 /// Special type name used in the `Uuid` serialization implementation to indicate a BSON
 /// UUID is being serialized or deserialized. The BSON serializers/deserializers will handle this
@@ -312,6 +318,20 @@ impl From<Uuid> for Bson {
     }
 }
 
+impl TryFrom<Bson> for Uuid {
+    type Error = Error;
+
+    /// Converts a binary value with the UUID subtype (4) into a [`Uuid`].
+    fn try_from(bson: Bson) -> Result<Self> {
+        match bson {
+            Bson::Binary(binary) => binary.to_uuid(),
+            other => Err(Error::UnexpectedType {
+                element_type: other.element_type(),
+            }),
+        }
+    }
+}
+
 #[cfg(feature = "uuid-0_8")]
 impl From<uuid_0_8::Uuid> for Uuid {
     fn from(u: uuid_0_8::Uuid) -> Self {
@@ -486,6 +506,17 @@ macro_rules! trait_impls {
             }
         }
 
+        #[cfg($feat)]
+        #[cfg_attr(docsrs, doc(cfg($feat)))]
+        impl TryFrom<Bson> for $u {
+            type Error = Error;
+
+            /// Converts a binary value with the UUID subtype (4) into a UUID.
+            fn try_from(bson: Bson) -> Result<Self> {
+                Uuid::try_from(bson).map(Into::into)
+            }
+        }
+
         #[cfg(all($feat, feature = "serde_with"))]
         #[cfg_attr(docsrs, doc(cfg(all($feat, feature = "serde_with"))))]
         impl<'de> serde_with::DeserializeAs<'de, $u> for crate::Uuid {
@@ -542,6 +573,13 @@ pub enum Error {
         /// The actual length of the data.
         length: usize,
     },
+
+    /// Error returned when converting a [`Bson`] value that isn't a binary value into a UUID.
+    #[non_exhaustive]
+    UnexpectedType {
+        /// The type of the value that was provided.
+        element_type: ElementType,
+    },
 }
 
 /// Alias for `Result<T, bson::uuid::Error>`.
@@ -571,6 +609,13 @@ impl fmt::Display for Error {
                     length
                 )
             }
+            Error::UnexpectedType { element_type } => {
+                write!(
+                    fmt,
+                    "expected a binary value to convert to UUID, instead got {:?}",
+                    element_type
+                )
+            }
         }
     }
 }
@@ -1,5 +1,7 @@
+use std::convert::TryFrom;
+
 use crate::{
-    spec::BinarySubtype,
+    spec::{BinarySubtype, ElementType},
     uuid::{Uuid, UuidRepresentation},
     Binary,
     Bson,
@@ -238,6 +240,102 @@ fn test_binary_to_uuid_python_legacy_rep() {
     );
 }
 
+#[test]
+fn bson_uuid_helpers() {
+    let uuid = Uuid::new();
+
+    let bson = Bson::new_uuid(uuid.bytes());
+    assert_eq!(bson, Bson::from(uuid));
+    assert_eq!(bson.as_uuid(), Some(uuid.bytes()));
+    assert_eq!(Uuid::try_from(bson).unwrap(), uuid);
+
+    let generic = Bson::Binary(Binary {
+        subtype: BinarySubtype::Generic,
+        bytes: uuid.bytes().to_vec(),
+    });
+    assert_eq!(generic.as_uuid(), None);
+    assert!(Uuid::try_from(generic).is_err());
+
+    let short = Bson::Binary(Binary {
+        subtype: BinarySubtype::Uuid,
+        bytes: vec![1, 2, 3],
+    });
+    assert_eq!(short.as_uuid(), None);
+    assert!(Uuid::try_from(short).is_err());
+
+    assert_eq!(Bson::Int32(1).as_uuid(), None);
+    assert!(matches!(
+        Uuid::try_from(Bson::Int32(1)),
+        Err(crate::uuid::Error::UnexpectedType {
+            element_type: ElementType::Int32,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn bson_legacy_uuid_helpers() {
+    let uuid = crate::Uuid::parse_str("00112233445566778899AABBCCDDEEFF").unwrap();
+    let stored = |rep| match Bson::new_legacy_uuid(uuid.bytes(), rep) {
+        Bson::Binary(binary) => binary,
+        other => panic!("expected binary, got {:?}", other),
+    };
+
+    let java = stored(UuidRepresentation::JavaLegacy);
+    assert_eq!(java.subtype, BinarySubtype::UuidOld);
+    assert_eq!(
+        java.bytes,
+        [
+            0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00, 0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa,
+            0x99, 0x88
+        ]
+    );
+
+    let csharp = stored(UuidRepresentation::CSharpLegacy);
+    assert_eq!(csharp.subtype, BinarySubtype::UuidOld);
+    assert_eq!(
+        csharp.bytes,
+        [
+            0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff
+        ]
+    );
+
+    let python = stored(UuidRepresentation::PythonLegacy);
+    assert_eq!(python.subtype, BinarySubtype::UuidOld);
+    assert_eq!(python.bytes, uuid.bytes());
+
+    assert_eq!(
+        Bson::new_legacy_uuid(uuid.bytes(), UuidRepresentation::Standard),
+        Bson::new_uuid(uuid.bytes())
+    );
+
+    for rep in [
+        UuidRepresentation::Standard,
+        UuidRepresentation::JavaLegacy,
+        UuidRepresentation::CSharpLegacy,
+        UuidRepresentation::PythonLegacy,
+    ] {
+        let bson = Bson::new_legacy_uuid(uuid.bytes(), rep);
+        assert_eq!(bson.as_legacy_uuid(rep), Some(uuid.bytes()));
+    }
+
+    // the subtype must match the representation
+    let standard = Bson::new_uuid(uuid.bytes());
+    assert_eq!(
+        standard.as_legacy_uuid(UuidRepresentation::JavaLegacy),
+        None
+    );
+    assert_eq!(
+        Bson::Binary(java).as_legacy_uuid(UuidRepresentation::Standard),
+        None
+    );
+    assert_eq!(
+        Bson::Int32(1).as_legacy_uuid(UuidRepresentation::PythonLegacy),
+        None
+    );
+}
+
 #[cfg(feature = "uuid-0_8")]
 #[test]
 fn interop_0_8() {
@@ -252,6 +350,10 @@ fn interop_0_8() {
     let d_bson = doc! { "uuid": uuid };
     let d_uuid = doc! { "uuid": uuid_uuid };
     assert_eq!(d_bson, d_uuid);
+    assert_eq!(
+        uuid_0_8::Uuid::try_from(Bson::from(uuid_uuid)).unwrap(),
+        uuid_uuid
+    );
 }
 
 #[cfg(feature = "uuid-1")]
@@ -268,4 +370,8 @@ fn interop_1() {
     let d_bson = doc! { "uuid": uuid };
     let d_uuid = doc! { "uuid": uuid_uuid };
     assert_eq!(d_bson, d_uuid);
+    assert_eq!(
+        uuid::Uuid::try_from(Bson::from(uuid_uuid)).unwrap(),
+        uuid_uuid
+    );
 }